@@ -0,0 +1,39 @@
+//  Description:
+//!   Implements [`arbitrary`]'s [`Arbitrary`] for the [`StackVec`],
+//!   available under the `arbitrary` feature.
+//
+
+use std::mem::MaybeUninit;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+impl<'a, const LEN: usize, T: Arbitrary<'a>, P> Arbitrary<'a> for StackVec<LEN, T, P> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut stack: Self = Self::new();
+        for elem in u.arbitrary_iter()?.take(LEN) {
+            // NOTE: Can't fail, as we take at most `LEN` elements
+            stack.data[stack.len] = MaybeUninit::new(elem?);
+            stack.len += 1;
+        }
+        Ok(stack)
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut stack: Self = Self::new();
+        for elem in u.arbitrary_take_rest_iter()?.take(LEN) {
+            // NOTE: Can't fail, as we take at most `LEN` elements
+            stack.data[stack.len] = MaybeUninit::new(elem?);
+            stack.len += 1;
+        }
+        Ok(stack)
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) { (0, None) }
+}
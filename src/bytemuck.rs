@@ -0,0 +1,32 @@
+//  Description:
+//!   Implements [`bytemuck`]'s traits for the [`StackVec`] where sound,
+//!   and adds byte views on StackVecs of [`Pod`] types. Available under the
+//!   `bytemuck` feature.
+//
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T: Pod, P> StackVec<LEN, T, P> {
+    /// Returns the initialized elements of this StackVec as raw bytes.
+    ///
+    /// # Returns
+    /// A [`&[u8]`] spanning all bytes of the first [`Self::len()`](StackVec::len()) elements.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { bytemuck::cast_slice(self.as_slice()) }
+
+    /// Returns the initialized elements of this StackVec as mutable raw bytes.
+    ///
+    /// Because `T` is [`Pod`], any bytes written are valid elements.
+    ///
+    /// # Returns
+    /// A [`&mut [u8]`] spanning all bytes of the first [`Self::len()`](StackVec::len()) elements.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] { bytemuck::cast_slice_mut(self.as_slice_mut()) }
+}
+
+// SAFETY: An all-zero StackVec has a length of zero, so none of its (zeroed) elements are considered initialized. This holds for any `T` and `P`.
+unsafe impl<const LEN: usize, T, P> Zeroable for StackVec<LEN, T, P> {}
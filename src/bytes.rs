@@ -0,0 +1,40 @@
+//  Description:
+//!   Implements [`bytes`]'s [`BufMut`] for byte [`StackVec`]s and [`Buf`]
+//!   for [`ByteCursor`]s, available under the `bytes` feature.
+//!
+//!   To read from a borrowed StackVec instead, use its slice (e.g.,
+//!   `stack_vec.as_slice()`), which already implements [`Buf`].
+//
+
+use bytes::buf::UninitSlice;
+use bytes::{Buf, BufMut};
+
+use crate::{ByteCursor, StackVec};
+
+
+/***** LIBRARY *****/
+// SAFETY: `chunk_mut()` only returns the spare capacity, and `advance_mut()` only marks bytes as initialized if the caller promises they are.
+unsafe impl<const LEN: usize, P> BufMut for StackVec<LEN, u8, P> {
+    #[inline]
+    fn remaining_mut(&self) -> usize { self.remaining_capacity() }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        // SAFETY: The caller promises that the next `cnt` bytes in the spare capacity are initialized (and thus that they exist).
+        unsafe { self.set_len(self.len() + cnt) };
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice { UninitSlice::uninit(self.spare_capacity_mut()) }
+}
+
+impl<const LEN: usize, P> Buf for ByteCursor<LEN, P> {
+    #[inline]
+    fn remaining(&self) -> usize { ByteCursor::remaining(self) }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] { self.remaining_slice() }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) { self.consume(cnt) }
+}
@@ -0,0 +1,274 @@
+//  Description:
+//!   Implements a [`StackCString`], a fixed-capacity, NUL-terminated
+//!   string that lives in the stack, for passing strings to C APIs without
+//!   allocating.
+//
+
+use std::error::Error;
+use std::ffi::{CStr, c_char};
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+use std::str::Utf8Error;
+
+use crate::{CapacityError, StackVec, const_assert};
+
+
+/***** ERRORS *****/
+/// Defines the errors that may occur when building a [`StackCString`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CStringError {
+    /// The bytes (and the terminator) did not fit in the StackCString.
+    Capacity(CapacityError),
+    /// The bytes contained a NUL-byte, which would terminate the string early.
+    InteriorNul {
+        /// The position of the NUL-byte in the given bytes.
+        pos: usize,
+    },
+}
+impl Display for CStringError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        match self {
+            Self::Capacity(err) => write!(f, "{err}"),
+            Self::InteriorNul { pos } => write!(f, "Found NUL-byte at position {pos} in bytes for StackCString"),
+        }
+    }
+}
+impl Error for CStringError {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-capacity, NUL-terminated byte string that lives in the stack.
+///
+/// The `LEN` bytes include the terminator, so the StackCString can hold at most `LEN - 1` other bytes. All operations keep the terminator in place, and reject interior NUL-bytes, such that [`Self::as_c_str()`](StackCString::as_c_str()) is always valid.
+#[derive(Clone, Eq, PartialEq)]
+pub struct StackCString<const LEN: usize> {
+    /// The bytes of the string.
+    ///
+    /// We implement the StackCString such that it upholds the following assertion: `vec` ends with a NUL-byte, and contains no other NUL-bytes.
+    vec: StackVec<LEN, u8>,
+}
+impl<const LEN: usize> Default for StackCString<LEN> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize> StackCString<LEN> {
+    /// Constructor for the StackCString that initializes it as empty.
+    ///
+    /// Refuses to compile if `LEN` is zero, as there would be no room for the terminator.
+    ///
+    /// # Returns
+    /// A new StackCString with only a terminator in it.
+    #[inline]
+    pub const fn new() -> Self {
+        // There must be room for the terminator
+        const { const_assert(LEN > 0, "Cannot create a StackCString of capacity 0") };
+
+        let mut vec: StackVec<LEN, u8> = StackVec::new();
+        vec.data[0] = MaybeUninit::new(0);
+        // SAFETY: We just initialized the first element.
+        vec.len = 1;
+        Self { vec }
+    }
+
+    /// Constructor for the StackCString that copies the given bytes.
+    ///
+    /// # Arguments
+    /// - `bytes`: The bytes to copy, without a terminator.
+    ///
+    /// # Returns
+    /// A new StackCString with `bytes` and a terminator in it.
+    ///
+    /// # Errors
+    /// This function errors if `bytes` contains a NUL-byte, or if it is longer than [`Self::capacity()`](StackCString::capacity()).
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CStringError> {
+        let mut res: Self = Self::new();
+        res.push_bytes(bytes)?;
+        Ok(res)
+    }
+
+    /// Appends a byte to the end of the StackCString, keeping the terminator after it.
+    ///
+    /// # Arguments
+    /// - `byte`: The byte to append.
+    ///
+    /// # Errors
+    /// This function errors if `byte` is a NUL-byte, or if the StackCString is full.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Result<(), CStringError> { self.push_bytes(&[byte]) }
+
+    /// Appends bytes to the end of the StackCString, keeping the terminator after them.
+    ///
+    /// Either all bytes are appended, or none are.
+    ///
+    /// # Arguments
+    /// - `bytes`: The bytes to append.
+    ///
+    /// # Errors
+    /// This function errors if `bytes` contains a NUL-byte, or if they don't all fit.
+    #[inline]
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), CStringError> {
+        if let Some(pos) = bytes.iter().position(|b| *b == 0) {
+            return Err(CStringError::InteriorNul { pos });
+        }
+        if bytes.len() > self.vec.remaining_capacity() {
+            return Err(CStringError::Capacity(CapacityError::with_excess((), LEN, bytes.len() - self.vec.remaining_capacity())));
+        }
+
+        // Overwrite the terminator with the bytes, then put it back
+        self.vec.len -= 1;
+        let _ = self.vec.try_extend_from_copy_slice(bytes);
+        let _ = self.vec.push_within_capacity(0);
+        Ok(())
+    }
+
+    /// Appends a string to the end of the StackCString, keeping the terminator after it.
+    ///
+    /// Either the whole string is appended, or nothing is.
+    ///
+    /// # Arguments
+    /// - `s`: The string to append.
+    ///
+    /// # Errors
+    /// This function errors if `s` contains a NUL-character, or if it doesn't fit.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) -> Result<(), CStringError> { self.push_bytes(s.as_bytes()) }
+
+    /// Removes the last byte (before the terminator) from the StackCString.
+    ///
+    /// # Returns
+    /// The removed byte, or [`None`] if the StackCString was empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<u8> {
+        let byte: u8 = *self.as_bytes().last()?;
+        self.truncate(self.len() - 1);
+        Some(byte)
+    }
+
+    /// Shortens the StackCString to the given length, moving the terminator accordingly.
+    ///
+    /// Does nothing if the StackCString is already shorter.
+    ///
+    /// # Arguments
+    /// - `len`: The new number of bytes (excluding the terminator).
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.vec.as_slice_mut()[len] = 0;
+            self.vec.truncate(len + 1);
+        }
+    }
+
+    /// Removes all bytes from the StackCString, leaving only the terminator.
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+
+    /// Returns the number of bytes in the StackCString, excluding the terminator.
+    ///
+    /// # Returns
+    /// The number of bytes before the terminator.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() - 1 }
+
+    /// Returns whether there are any bytes (before the terminator) in the StackCString.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the maximum number of bytes the StackCString can hold, excluding the terminator.
+    ///
+    /// # Returns
+    /// `LEN - 1`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN - 1 }
+
+    /// Returns the bytes in the StackCString, excluding the terminator.
+    ///
+    /// # Returns
+    /// A slice of [`Self::len()`](StackCString::len()) bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { &self.vec.as_slice()[..self.len()] }
+
+    /// Returns the bytes in the StackCString, including the terminator.
+    ///
+    /// # Returns
+    /// A slice of [`Self::len()`](StackCString::len()) + 1 bytes, the last of which is NUL.
+    #[inline]
+    pub fn as_bytes_with_nul(&self) -> &[u8] { self.vec.as_slice() }
+
+    /// Returns the StackCString as a [`CStr`].
+    ///
+    /// # Returns
+    /// A [`&CStr`](CStr) borrowing the bytes of the StackCString.
+    #[inline]
+    pub fn as_c_str(&self) -> &CStr {
+        // SAFETY: We use our assertion for `self.vec` that it ends with a NUL-byte and contains no others.
+        unsafe { CStr::from_bytes_with_nul_unchecked(self.vec.as_slice()) }
+    }
+
+    /// Returns a raw pointer to the StackCString, to pass to C.
+    ///
+    /// The pointer is only valid for as long as the StackCString is not moved or modified, and must never be written to.
+    ///
+    /// # Returns
+    /// A `*const c_char` pointing to the first byte of the NUL-terminated string.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const c_char { self.vec.as_ptr() as *const c_char }
+
+    /// Returns the StackCString as a string, if it is valid UTF-8.
+    ///
+    /// # Returns
+    /// A [`&str`] borrowing the bytes of the StackCString (excluding the terminator).
+    ///
+    /// # Errors
+    /// This function errors if the bytes are not valid UTF-8.
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, Utf8Error> { std::str::from_utf8(self.as_bytes()) }
+}
+
+impl<const LEN: usize> Debug for StackCString<LEN> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_c_str(), f) }
+}
+
+impl<const LEN: usize> Deref for StackCString<LEN> {
+    type Target = CStr;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_c_str() }
+}
+impl<const LEN: usize> AsRef<CStr> for StackCString<LEN> {
+    #[inline]
+    fn as_ref(&self) -> &CStr { self.as_c_str() }
+}
+
+impl<const LEN: usize> TryFrom<&[u8]> for StackCString<LEN> {
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> { Self::from_bytes(value) }
+}
+impl<const LEN: usize> TryFrom<&str> for StackCString<LEN> {
+    type Error = CStringError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> { Self::from_bytes(value.as_bytes()) }
+}
+impl<const LEN: usize> TryFrom<&CStr> for StackCString<LEN> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(value: &CStr) -> Result<Self, Self::Error> {
+        let mut vec: StackVec<LEN, u8> = StackVec::new();
+        vec.try_extend_from_copy_slice(value.to_bytes_with_nul())?;
+        // SAFETY: A `CStr` upholds the same assertion as we do.
+        Ok(Self { vec })
+    }
+}
@@ -0,0 +1,203 @@
+//  Description:
+//!   Defines the [`ContiguousVec`] trait, which abstracts over growable,
+//!   contiguous containers such as [`StackVec`]s and [`Vec`]s.
+//
+
+use crate::{CapacityError, InsertError, LenType, StackVec};
+
+
+/***** LIBRARY *****/
+/// Abstracts over growable containers that store their elements contiguously, such as the [`StackVec`] and the [`Vec`].
+///
+/// This allows writing algorithms that are generic over the storage, letting the caller choose whether it lives in the stack or on the heap. Note that the methods mirror those of the [`StackVec`], so e.g. [`Self::remove()`](ContiguousVec::remove()) returns an [`Option`] instead of panicking.
+pub trait ContiguousVec<T> {
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the end of the container.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function panics if the container has no room for the element. For [`StackVec`]s, this is regardless of their [`OverflowPolicy`](crate::OverflowPolicy).
+    #[inline]
+    #[track_caller]
+    fn push(&mut self, elem: T) {
+        if let Err(err) = self.try_push(elem) {
+            panic!("{err}");
+        }
+    }
+
+    /// Pushes a new element to the end of the container, returning an error if there is no room for it.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the container is full (or, for [`Vec`]s, it fails to allocate), a [`CapacityError`] is returned that carries the given `elem`. The container is untouched in that case.
+    fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>>;
+
+    /// Removes the last element from the container.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the container was empty.
+    fn pop(&mut self) -> Option<T>;
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element at the given position.
+    ///
+    /// # Arguments
+    /// - `idx`: The position to insert the element at. Elements at and after it are moved one place back.
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Panics
+    /// This function panics if `idx` is greater than the length of the container, or if the container has no room for the element.
+    #[inline]
+    #[track_caller]
+    fn insert(&mut self, idx: usize, elem: T) {
+        if let Err(err) = self.try_insert(idx, elem) {
+            panic!("{err}");
+        }
+    }
+
+    /// Inserts a new element at the given position, returning an error if that fails.
+    ///
+    /// # Arguments
+    /// - `idx`: The position to insert the element at. Elements at and after it are moved one place back.
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Errors
+    /// An [`InsertError`] is returned that carries the given `elem` if `idx` is out-of-bounds or the container has no room for it. The container is untouched in that case.
+    fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), InsertError<T>>;
+
+    /// Removes an element from the container, preserving the order of the other elements.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to remove.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if `idx` is out-of-bounds.
+    fn remove(&mut self, idx: usize) -> Option<T>;
+
+    /// Shortens the container to the given length, dropping any elements beyond it.
+    ///
+    /// If `len` is greater than or equal to the current length, this does nothing.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the container.
+    fn truncate(&mut self, len: usize);
+
+    /// Removes _all_ elements from the container.
+    #[inline]
+    fn clear(&mut self) { self.truncate(0) }
+
+    /// Returns the elements in the container.
+    ///
+    /// # Returns
+    /// A slice of all elements, in order.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns the elements in the container, mutably.
+    ///
+    /// # Returns
+    /// A mutable slice of all elements, in order.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Returns the number of elements in the container.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    fn len(&self) -> usize { self.as_slice().len() }
+
+    /// Returns whether there are any elements in the container.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the container can hold without failing (for [`StackVec`]s) or reallocating (for [`Vec`]s).
+    ///
+    /// # Returns
+    /// The current capacity of the container.
+    fn capacity(&self) -> usize;
+}
+
+impl<const LEN: usize, T, P, L: LenType> ContiguousVec<T> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> { StackVec::try_push(self, elem) }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> { StackVec::pop(self) }
+
+    #[inline]
+    fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), InsertError<T>> { StackVec::try_insert(self, idx, elem) }
+
+    #[inline]
+    fn remove(&mut self, idx: usize) -> Option<T> { StackVec::remove(self, idx) }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) { StackVec::truncate(self, len) }
+
+    #[inline]
+    fn clear(&mut self) { StackVec::clear(self) }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] { StackVec::as_slice(self) }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] { StackVec::as_slice_mut(self) }
+
+    #[inline]
+    fn len(&self) -> usize { StackVec::len(self) }
+
+    #[inline]
+    fn capacity(&self) -> usize { LEN }
+}
+
+impl<T> ContiguousVec<T> for Vec<T> {
+    #[inline]
+    fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.try_reserve(1).is_err() {
+            return Err(CapacityError::new(elem, self.capacity()));
+        }
+        Vec::push(self, elem);
+        Ok(())
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> { Vec::pop(self) }
+
+    #[inline]
+    fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), InsertError<T>> {
+        if idx > self.len() {
+            return Err(InsertError::OutOfBounds { idx, len: self.len(), elem });
+        }
+        if self.try_reserve(1).is_err() {
+            return Err(InsertError::Capacity(CapacityError::new(elem, self.capacity())));
+        }
+        Vec::insert(self, idx, elem);
+        Ok(())
+    }
+
+    #[inline]
+    fn remove(&mut self, idx: usize) -> Option<T> { if idx < self.len() { Some(Vec::remove(self, idx)) } else { None } }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) { Vec::truncate(self, len) }
+
+    #[inline]
+    fn clear(&mut self) { Vec::clear(self) }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] { Vec::as_slice(self) }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] { Vec::as_mut_slice(self) }
+
+    #[inline]
+    fn len(&self) -> usize { Vec::len(self) }
+
+    #[inline]
+    fn capacity(&self) -> usize { Vec::capacity(self) }
+}
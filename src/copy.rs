@@ -0,0 +1,311 @@
+//  Description:
+//!   Implements a [`CopyStackVec`], a variant of the [`StackVec`] for [`Copy`]
+//!   elements that is itself [`Copy`].
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+
+use crate::{CapacityError, IntoIter, StackVec};
+
+
+/***** LIBRARY *****/
+/// Implements a variant of the [`StackVec`] for [`Copy`] elements that is itself [`Copy`].
+///
+/// A StackVec can't be [`Copy`], because it has to implement [`Drop`] for elements that need dropping. Copy elements never do, so the CopyStackVec can leave that out. This means it can be passed around by value freely and stored in [`Cell`](std::cell::Cell)s.
+///
+/// It has the most common StackVec operations, and dereferences to a slice for the rest. Convert it to a StackVec (with [`From`]) to get the full interface.
+#[derive(Clone, Copy)]
+pub struct CopyStackVec<const LEN: usize, T: Copy> {
+    /// The data array that we wrap.
+    data: [MaybeUninit<T>; LEN],
+    /// The current number of initialized elements.
+    ///
+    /// We implement the CopyStackVec such that is upholds the following assertion: the first `len` elements of `data` are initialized.
+    len:  usize,
+}
+impl<const LEN: usize, T: Copy> Default for CopyStackVec<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T: Copy> CopyStackVec<LEN, T> {
+    /// Constructor for the CopyStackVec that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new CopyStackVec with no elements in it.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still.
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            len:  0,
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the end of the CopyStackVec.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function panics if the CopyStackVec is already full.
+    #[inline]
+    #[track_caller]
+    pub const fn push(&mut self, elem: T) {
+        if self.try_push(elem).is_err() {
+            panic!("Cannot push element to full CopyStackVec");
+        }
+    }
+
+    /// Pushes a new element to the end of the CopyStackVec, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push()`](CopyStackVec::push()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the CopyStackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub const fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.len >= LEN {
+            return Err(CapacityError::new(elem, LEN));
+        }
+        self.data[self.len] = MaybeUninit::new(elem);
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last element from the CopyStackVec.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the CopyStackVec was empty.
+    #[inline]
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // SAFETY: The element at the old `self.len - 1` was initialized, and it's `Copy`, so reading it leaves nothing to drop.
+        Some(unsafe { self.data[self.len].assume_init() })
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Appends all elements in the given slice to the end of the CopyStackVec.
+    ///
+    /// # Arguments
+    /// - `elems`: The elements to append.
+    ///
+    /// # Panics
+    /// This function panics if the elements do not all fit. In that case, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, elems: &[T]) {
+        if self.try_extend_from_slice(elems).is_err() {
+            panic!("Cannot extend CopyStackVec of length {} and capacity {} with {} elements", self.len, LEN, elems.len());
+        }
+    }
+
+    /// Appends all elements in the given slice to the end of the CopyStackVec, returning an error if they do not all fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::extend_from_slice()`](CopyStackVec::extend_from_slice()).
+    ///
+    /// # Arguments
+    /// - `elems`: The elements to append.
+    ///
+    /// # Errors
+    /// If the elements do not all fit, a [`CapacityError`] is returned that carries the number of elements that would not have fit. The vec is untouched in that case.
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, elems: &[T]) -> Result<(), CapacityError> {
+        if elems.len() > LEN - self.len {
+            return Err(CapacityError::with_excess((), LEN, self.len + elems.len() - LEN));
+        }
+        // SAFETY: We just checked that `elems` fits after the initialized elements, and since `self` is borrowed mutably, they can't overlap.
+        unsafe { std::ptr::copy_nonoverlapping(elems.as_ptr(), self.as_mut_ptr().add(self.len), elems.len()) };
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the elements after the old length.
+        self.len += elems.len();
+        Ok(())
+    }
+
+    /// Shortens the CopyStackVec to the given length.
+    ///
+    /// Does nothing if the CopyStackVec is already shorter.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the CopyStackVec.
+    #[inline]
+    pub const fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            // NOTE: No need to drop anything, as the elements are `Copy`
+            self.len = len;
+        }
+    }
+
+    /// Removes all elements from the CopyStackVec.
+    #[inline]
+    pub const fn clear(&mut self) { self.len = 0 }
+
+    /// Returns the elements in the CopyStackVec.
+    ///
+    /// # Returns
+    /// A slice of all elements.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `self.len` elements are initialized, by our `self.len` assertion.
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns the elements in the CopyStackVec, mutably.
+    ///
+    /// # Returns
+    /// A mutable slice of all elements.
+    #[inline]
+    pub const fn as_slice_mut(&mut self) -> &mut [T] {
+        // SAFETY: The first `self.len` elements are initialized, by our `self.len` assertion.
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Returns a raw pointer to the CopyStackVec's buffer.
+    ///
+    /// # Returns
+    /// A `*const T` pointing to the first element. Only the first [`Self::len()`](CopyStackVec::len()) elements are initialized.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T { self.data.as_ptr() as *const T }
+
+    /// Returns a raw, mutable pointer to the CopyStackVec's buffer.
+    ///
+    /// # Returns
+    /// A `*mut T` pointing to the first element. Only the first [`Self::len()`](CopyStackVec::len()) elements are initialized.
+    #[inline]
+    pub const fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() as *mut T }
+
+    /// Returns the number of elements in the CopyStackVec.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns whether there are any elements in the CopyStackVec.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns whether the CopyStackVec has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len >= LEN }
+
+    /// Returns the number of elements that can still be pushed to the CopyStackVec.
+    ///
+    /// # Returns
+    /// The capacity minus the length.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { LEN - self.len }
+
+    /// Returns the maximum number of elements the CopyStackVec can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+
+impl<const LEN: usize, T: Copy + Debug> Debug for CopyStackVec<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_slice(), f) }
+}
+impl<const LEN: usize, T: Copy + Eq> Eq for CopyStackVec<LEN, T> {}
+impl<const LEN: usize, T: Copy + Hash> Hash for CopyStackVec<LEN, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+impl<const LEN: usize, const LEN2: usize, T: Copy + PartialEq> PartialEq<CopyStackVec<LEN2, T>> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &CopyStackVec<LEN2, T>) -> bool { self.as_slice() == other.as_slice() }
+}
+impl<const LEN: usize, const LEN2: usize, T: Copy + PartialEq> PartialEq<[T; LEN2]> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T; LEN2]) -> bool { self.as_slice() == other }
+}
+impl<const LEN: usize, T: Copy + PartialEq> PartialEq<[T]> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+// Deref
+impl<const LEN: usize, T: Copy> Deref for CopyStackVec<LEN, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+impl<const LEN: usize, T: Copy> DerefMut for CopyStackVec<LEN, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target { self.as_slice_mut() }
+}
+impl<const LEN: usize, T: Copy> AsRef<[T]> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+impl<const LEN: usize, T: Copy> AsMut<[T]> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+
+// Iteration
+impl<const LEN: usize, T: Copy> IntoIterator for CopyStackVec<LEN, T> {
+    type Item = T;
+    type IntoIter = IntoIter<LEN, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { StackVec::<LEN, T>::from(self).into_iter() }
+}
+impl<'s, const LEN: usize, T: Copy> IntoIterator for &'s CopyStackVec<LEN, T> {
+    type Item = &'s T;
+    type IntoIter = std::slice::Iter<'s, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_slice().iter() }
+}
+impl<'s, const LEN: usize, T: Copy> IntoIterator for &'s mut CopyStackVec<LEN, T> {
+    type Item = &'s mut T;
+    type IntoIter = std::slice::IterMut<'s, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_slice_mut().iter_mut() }
+}
+
+// Conversion
+impl<const LEN: usize, T: Copy> TryFrom<&[T]> for CopyStackVec<LEN, T> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut res: Self = Self::new();
+        res.try_extend_from_slice(value)?;
+        Ok(res)
+    }
+}
+impl<const LEN: usize, T: Copy, P> From<StackVec<LEN, T, P>> for CopyStackVec<LEN, T> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        // NOTE: Copying the data is fine, as the elements are `Copy` and `value` is dropped without dropping them
+        Self { data: value.data, len: value.len }
+    }
+}
+impl<const LEN: usize, T: Copy, P> From<CopyStackVec<LEN, T>> for StackVec<LEN, T, P> {
+    #[inline]
+    fn from(value: CopyStackVec<LEN, T>) -> Self {
+        // SAFETY: The CopyStackVec upholds the same assertion as we do.
+        unsafe { StackVec::from_parts(value.data, value.len) }
+    }
+}
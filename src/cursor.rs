@@ -0,0 +1,103 @@
+//  Description:
+//!   Implements a [`ByteCursor`], which reads from a byte [`StackVec`]
+//!   through [`std::io::Read`] and [`std::io::BufRead`].
+//
+
+use std::io::{self, BufRead, Read};
+
+use crate::{PanicOnFull, StackVec};
+
+
+/***** LIBRARY *****/
+/// Reads the bytes of a byte [`StackVec`] through [`Read`] and [`BufRead`], tracking how far it has read.
+///
+/// This is like [`std::io::Cursor`], except that it only reads and owns its StackVec.
+#[derive(Clone, Debug)]
+pub struct ByteCursor<const LEN: usize, P = PanicOnFull> {
+    /// The bytes that we read from.
+    vec: StackVec<LEN, u8, P>,
+    /// The position of the next byte to read in `vec`.
+    pos: usize,
+}
+impl<const LEN: usize, P> ByteCursor<LEN, P> {
+    /// Constructor for the ByteCursor.
+    ///
+    /// # Arguments
+    /// - `vec`: The StackVec to read the bytes of.
+    ///
+    /// # Returns
+    /// A new ByteCursor that starts reading at the first byte in `vec`.
+    #[inline]
+    pub const fn new(vec: StackVec<LEN, u8, P>) -> Self { Self { vec, pos: 0 } }
+
+    /// Returns the number of bytes read so far.
+    ///
+    /// # Returns
+    /// The position of the next byte to read in the StackVec.
+    #[inline]
+    pub const fn position(&self) -> usize { self.pos }
+
+    /// Returns the number of bytes that haven't been read yet.
+    ///
+    /// # Returns
+    /// The number of remaining bytes.
+    #[inline]
+    pub const fn remaining(&self) -> usize { self.vec.len() - self.pos }
+
+    /// Returns the bytes that haven't been read yet.
+    ///
+    /// # Returns
+    /// A slice of the remaining bytes in the StackVec.
+    #[inline]
+    pub fn remaining_slice(&self) -> &[u8] { &self.vec.as_slice()[self.pos..] }
+
+    /// Marks bytes as read, without returning them.
+    ///
+    /// # Arguments
+    /// - `amt`: The number of bytes to skip. If this is more than [`Self::remaining()`](ByteCursor::remaining()), only the remaining bytes are skipped.
+    #[inline]
+    pub fn consume(&mut self, amt: usize) { self.pos += amt.min(self.remaining()); }
+
+    /// Returns the StackVec we read from.
+    ///
+    /// # Returns
+    /// A reference to the StackVec, including the bytes that have already been read.
+    #[inline]
+    pub const fn get_ref(&self) -> &StackVec<LEN, u8, P> { &self.vec }
+
+    /// Returns the StackVec we read from, consuming the ByteCursor.
+    ///
+    /// # Returns
+    /// The StackVec, including the bytes that have already been read.
+    #[inline]
+    pub fn into_inner(self) -> StackVec<LEN, u8, P> { self.vec }
+
+    /// Reads as many bytes as fit in `buf`.
+    ///
+    /// # Returns
+    /// The number of bytes that were read.
+    #[inline]
+    pub(crate) fn read_truncated(&mut self, buf: &mut [u8]) -> usize {
+        let n: usize = buf.len().min(self.remaining());
+        buf[..n].copy_from_slice(&self.remaining_slice()[..n]);
+        self.pos += n;
+        n
+    }
+}
+
+impl<const LEN: usize, P> Read for ByteCursor<LEN, P> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { Ok(self.read_truncated(buf)) }
+}
+impl<const LEN: usize, P> BufRead for ByteCursor<LEN, P> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> { Ok(self.remaining_slice()) }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) { ByteCursor::consume(self, amt) }
+}
+
+impl<const LEN: usize, P> From<StackVec<LEN, u8, P>> for ByteCursor<LEN, P> {
+    #[inline]
+    fn from(value: StackVec<LEN, u8, P>) -> Self { Self::new(value) }
+}
@@ -0,0 +1,556 @@
+//  Description:
+//!   Implements a [`StackDeque`], a fixed-capacity double-ended queue that
+//!   lives in the stack.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::mem::MaybeUninit;
+#[cfg(not(feature = "no-panic"))]
+use std::ops::{Index, IndexMut};
+
+use crate::{CapacityError, StackVec};
+
+
+/***** ITERATORS *****/
+/// Iterates over the elements of a [`StackDeque`] by reference.
+pub struct DequeIter<'d, T> {
+    /// The elements at the front, up to the end of the storage.
+    front: std::slice::Iter<'d, T>,
+    /// The elements that wrapped around to the start of the storage.
+    back:  std::slice::Iter<'d, T>,
+}
+impl<'d, T> Clone for DequeIter<'d, T> {
+    #[inline]
+    fn clone(&self) -> Self { Self { front: self.front.clone(), back: self.back.clone() } }
+}
+impl<'d, T> Iterator for DequeIter<'d, T> {
+    type Item = &'d T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.front.next().or_else(|| self.back.next()) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+impl<'d, T> DoubleEndedIterator for DequeIter<'d, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.back.next_back().or_else(|| self.front.next_back()) }
+}
+impl<'d, T> ExactSizeIterator for DequeIter<'d, T> {}
+impl<'d, T> FusedIterator for DequeIter<'d, T> {}
+
+/// Iterates over the elements of a [`StackDeque`] by mutable reference.
+pub struct DequeIterMut<'d, T> {
+    /// The elements at the front, up to the end of the storage.
+    front: std::slice::IterMut<'d, T>,
+    /// The elements that wrapped around to the start of the storage.
+    back:  std::slice::IterMut<'d, T>,
+}
+impl<'d, T> Iterator for DequeIterMut<'d, T> {
+    type Item = &'d mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.front.next().or_else(|| self.back.next()) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+impl<'d, T> DoubleEndedIterator for DequeIterMut<'d, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.back.next_back().or_else(|| self.front.next_back()) }
+}
+impl<'d, T> ExactSizeIterator for DequeIterMut<'d, T> {}
+impl<'d, T> FusedIterator for DequeIterMut<'d, T> {}
+
+/// Iterates over a [`StackDeque`] by ownership.
+pub struct DequeIntoIter<const LEN: usize, T> {
+    /// The deque that we pop the elements from.
+    deque: StackDeque<LEN, T>,
+}
+impl<const LEN: usize, T: Clone> Clone for DequeIntoIter<LEN, T> {
+    #[inline]
+    fn clone(&self) -> Self { Self { deque: self.deque.clone() } }
+}
+impl<const LEN: usize, T: Debug> Debug for DequeIntoIter<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_tuple("DequeIntoIter").field(&self.deque).finish() }
+}
+impl<const LEN: usize, T> Iterator for DequeIntoIter<LEN, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.deque.pop_front() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.deque.len(), Some(self.deque.len())) }
+}
+impl<const LEN: usize, T> DoubleEndedIterator for DequeIntoIter<LEN, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.deque.pop_back() }
+}
+impl<const LEN: usize, T> ExactSizeIterator for DequeIntoIter<LEN, T> {}
+impl<const LEN: usize, T> FusedIterator for DequeIntoIter<LEN, T> {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-capacity, double-ended queue that lives in the stack.
+///
+/// It is a ring buffer over `LEN` inline slots, such that pushing and popping at either end is O(1). Like a [`VecDeque`](std::collections::VecDeque), its elements are not necessarily contiguous; use [`Self::as_slices()`](StackDeque::as_slices()) or [`Self::make_contiguous()`](StackDeque::make_contiguous()) to access them as slices.
+pub struct StackDeque<const LEN: usize, T> {
+    /// The data array that we wrap.
+    data: [MaybeUninit<T>; LEN],
+    /// The index in `data` of the first element. Always below `LEN` (or 0 if `LEN` is 0).
+    head: usize,
+    /// The current number of initialized elements.
+    ///
+    /// We implement the StackDeque such that it upholds the following assertion: the `len` elements of `data` starting at `head` (wrapping around at `LEN`) are initialized.
+    len:  usize,
+}
+impl<const LEN: usize, T> Default for StackDeque<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> StackDeque<LEN, T> {
+    /// Constructor for the StackDeque that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackDeque with no elements in it.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still.
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            head: 0,
+            len:  0,
+        }
+    }
+
+    /// Translates a logical index into an index in the data array.
+    ///
+    /// # Arguments
+    /// - `idx`: The logical index, which must be below `LEN`.
+    ///
+    /// # Returns
+    /// The index in `self.data` where the `idx`th element lives.
+    #[inline]
+    const fn physical(&self, idx: usize) -> usize {
+        // NOTE: Can't overflow, as both are below `LEN`
+        let idx: usize = self.head + idx;
+        if idx >= LEN { idx - LEN } else { idx }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the back of the StackDeque.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function panics if the StackDeque is already full.
+    #[inline]
+    #[track_caller]
+    pub fn push_back(&mut self, elem: T) {
+        if self.try_push_back(elem).is_err() {
+            panic!("Cannot push element to full StackDeque of capacity {LEN}");
+        }
+    }
+
+    /// Pushes a new element to the back of the StackDeque, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push_back()`](StackDeque::push_back()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackDeque is already full, a [`CapacityError`] is returned that carries the given `elem`. The deque is untouched in that case.
+    #[inline]
+    pub fn try_push_back(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(elem, LEN));
+        }
+        let idx: usize = self.physical(self.len);
+        self.data[idx].write(elem);
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the slot right after the last element.
+        self.len += 1;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the front of the StackDeque.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function panics if the StackDeque is already full.
+    #[inline]
+    #[track_caller]
+    pub fn push_front(&mut self, elem: T) {
+        if self.try_push_front(elem).is_err() {
+            panic!("Cannot push element to full StackDeque of capacity {LEN}");
+        }
+    }
+
+    /// Pushes a new element to the front of the StackDeque, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push_front()`](StackDeque::push_front()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackDeque is already full, a [`CapacityError`] is returned that carries the given `elem`. The deque is untouched in that case.
+    #[inline]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(elem, LEN));
+        }
+        // NOTE: `LEN` is at least 1 here, as we're not full
+        self.head = if self.head == 0 { LEN - 1 } else { self.head - 1 };
+        self.data[self.head].write(elem);
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the slot right before the first element.
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the first element from the StackDeque.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the StackDeque was empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let idx: usize = self.head;
+        self.head = self.physical(1);
+        self.len -= 1;
+        if self.len == 0 {
+            self.head = 0;
+        }
+        // SAFETY: The element at `idx` was initialized, and we just moved it out of the initialized range so it's never read again.
+        Some(unsafe { self.data[idx].assume_init_read() })
+    }
+
+    /// Removes the last element from the StackDeque.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the StackDeque was empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let idx: usize = self.physical(self.len);
+        if self.len == 0 {
+            self.head = 0;
+        }
+        // SAFETY: The element at `idx` was initialized, and we just moved it out of the initialized range so it's never read again.
+        Some(unsafe { self.data[idx].assume_init_read() })
+    }
+
+    /// Removes all elements from the StackDeque.
+    #[inline]
+    pub fn clear(&mut self) {
+        let (front, back): (&mut [T], &mut [T]) = self.as_mut_slices();
+        let (front, back): (*mut [T], *mut [T]) = (front, back);
+        // Forget the elements first, so a panicking destructor doesn't cause them to be dropped again
+        self.head = 0;
+        self.len = 0;
+        if std::mem::needs_drop::<T>() {
+            // SAFETY: Both slices pointed to initialized elements, which we just made sure we never touch again.
+            unsafe {
+                std::ptr::drop_in_place(front);
+                std::ptr::drop_in_place(back);
+            }
+        }
+    }
+
+    /// Returns a reference to the element at the given position.
+    ///
+    /// # Arguments
+    /// - `idx`: The position of the element, counting from the front.
+    ///
+    /// # Returns
+    /// A reference to the element, or [`None`] if `idx` is out-of-bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx < self.len {
+            // SAFETY: All elements within `self.len` of the head are initialized.
+            Some(unsafe { self.data[self.physical(idx)].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at the given position.
+    ///
+    /// # Arguments
+    /// - `idx`: The position of the element, counting from the front.
+    ///
+    /// # Returns
+    /// A mutable reference to the element, or [`None`] if `idx` is out-of-bounds.
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if idx < self.len {
+            let idx: usize = self.physical(idx);
+            // SAFETY: All elements within `self.len` of the head are initialized.
+            Some(unsafe { self.data[idx].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first element in the StackDeque.
+    ///
+    /// # Returns
+    /// A reference to the first element, or [`None`] if the StackDeque is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> { self.get(0) }
+
+    /// Returns the first element in the StackDeque, mutably.
+    ///
+    /// # Returns
+    /// A mutable reference to the first element, or [`None`] if the StackDeque is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> { self.get_mut(0) }
+
+    /// Returns the last element in the StackDeque.
+    ///
+    /// # Returns
+    /// A reference to the last element, or [`None`] if the StackDeque is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> { self.get(self.len.wrapping_sub(1)) }
+
+    /// Returns the last element in the StackDeque, mutably.
+    ///
+    /// # Returns
+    /// A mutable reference to the last element, or [`None`] if the StackDeque is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> { self.get_mut(self.len.wrapping_sub(1)) }
+
+    /// Returns the elements in the StackDeque as two slices.
+    ///
+    /// # Returns
+    /// A tuple of the elements from the front up to the end of the storage, and the elements that wrapped around to its start. Together, they contain all elements in order. The second slice is empty if the elements are contiguous.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let ptr: *const T = self.data.as_ptr() as *const T;
+        let front_len: usize = self.len.min(LEN - self.head);
+        // SAFETY: The `front_len` elements from the head and the `self.len - front_len` elements from the start of `data` are exactly the initialized ones, by our `self.len` assertion.
+        unsafe { (std::slice::from_raw_parts(ptr.add(self.head), front_len), std::slice::from_raw_parts(ptr, self.len - front_len)) }
+    }
+
+    /// Returns the elements in the StackDeque as two mutable slices.
+    ///
+    /// # Returns
+    /// A tuple of the elements from the front up to the end of the storage, and the elements that wrapped around to its start. Together, they contain all elements in order. The second slice is empty if the elements are contiguous.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let ptr: *mut T = self.data.as_mut_ptr() as *mut T;
+        let front_len: usize = self.len.min(LEN - self.head);
+        // SAFETY: The `front_len` elements from the head and the `self.len - front_len` elements from the start of `data` are exactly the initialized ones, by our `self.len` assertion. They never overlap, as there are at most `LEN` of them.
+        unsafe { (std::slice::from_raw_parts_mut(ptr.add(self.head), front_len), std::slice::from_raw_parts_mut(ptr, self.len - front_len)) }
+    }
+
+    /// Rearranges the elements of the StackDeque such that they are contiguous.
+    ///
+    /// This is O(`LEN`) if the elements wrap around, and O(1) otherwise.
+    ///
+    /// # Returns
+    /// A mutable slice of all elements, in order.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > LEN {
+            // Rotating the whole array moves the head to the start, and the wrapped elements right after the others
+            self.data.rotate_left(self.head);
+            self.head = 0;
+        }
+        self.as_mut_slices().0
+    }
+
+    /// Returns an iterator over the elements in the StackDeque.
+    ///
+    /// # Returns
+    /// A [`DequeIter`] yielding references to the elements from front to back.
+    #[inline]
+    pub fn iter(&self) -> DequeIter<'_, T> {
+        let (front, back): (&[T], &[T]) = self.as_slices();
+        DequeIter { front: front.iter(), back: back.iter() }
+    }
+
+    /// Returns an iterator over the elements in the StackDeque, mutably.
+    ///
+    /// # Returns
+    /// A [`DequeIterMut`] yielding mutable references to the elements from front to back.
+    #[inline]
+    pub fn iter_mut(&mut self) -> DequeIterMut<'_, T> {
+        let (front, back): (&mut [T], &mut [T]) = self.as_mut_slices();
+        DequeIterMut { front: front.iter_mut(), back: back.iter_mut() }
+    }
+
+    /// Returns the number of elements in the StackDeque.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns whether there are any elements in the StackDeque.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns whether the StackDeque has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len >= LEN }
+
+    /// Returns the number of elements that can still be pushed to the StackDeque.
+    ///
+    /// # Returns
+    /// The capacity minus the length.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { LEN - self.len }
+
+    /// Returns the maximum number of elements the StackDeque can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+
+// Things we usually derive, but require some special attention
+impl<const LEN: usize, T: Clone> Clone for StackDeque<LEN, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut res: Self = Self::new();
+        for elem in self {
+            // NOTE: Can't fail, as we never have more than `LEN` elements
+            let _ = res.try_push_back(elem.clone());
+        }
+        res
+    }
+}
+impl<const LEN: usize, T: Debug> Debug for StackDeque<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_list().entries(self).finish() }
+}
+impl<const LEN: usize, T> Drop for StackDeque<LEN, T> {
+    #[inline]
+    fn drop(&mut self) { self.clear() }
+}
+impl<const LEN: usize, T: Eq> Eq for StackDeque<LEN, T> {}
+impl<const LEN: usize, T: Hash> Hash for StackDeque<LEN, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash like a contiguous sequence, regardless of where the elements wrap around
+        state.write_usize(self.len);
+        for elem in self {
+            elem.hash(state);
+        }
+    }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<StackDeque<LEN2, T>> for StackDeque<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &StackDeque<LEN2, T>) -> bool { self.iter().eq(other) }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<[T; LEN2]> for StackDeque<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T; LEN2]) -> bool { self.iter().eq(other) }
+}
+
+// Indexing
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T> Index<usize> for StackDeque<LEN, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, idx: usize) -> &Self::Output {
+        match self.get(idx) {
+            Some(elem) => elem,
+            None => panic!("Index {idx} is out-of-bounds for StackDeque of length {}", self.len),
+        }
+    }
+}
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T> IndexMut<usize> for StackDeque<LEN, T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        let len: usize = self.len;
+        match self.get_mut(idx) {
+            Some(elem) => elem,
+            None => panic!("Index {idx} is out-of-bounds for StackDeque of length {len}"),
+        }
+    }
+}
+
+// Iteration
+impl<const LEN: usize, T> IntoIterator for StackDeque<LEN, T> {
+    type Item = T;
+    type IntoIter = DequeIntoIter<LEN, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { DequeIntoIter { deque: self } }
+}
+impl<'d, const LEN: usize, T> IntoIterator for &'d StackDeque<LEN, T> {
+    type Item = &'d T;
+    type IntoIter = DequeIter<'d, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl<'d, const LEN: usize, T> IntoIterator for &'d mut StackDeque<LEN, T> {
+    type Item = &'d mut T;
+    type IntoIter = DequeIterMut<'d, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+// Conversion
+impl<const LEN: usize, T, P> From<StackVec<LEN, T, P>> for StackDeque<LEN, T> {
+    /// Takes over the elements of a [`StackVec`] in O(1), as they are already contiguous.
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        let mut res: Self = Self::new();
+        // SAFETY: We take over both the elements and the length, and forget the StackVec below so it doesn't drop them.
+        unsafe { std::ptr::copy_nonoverlapping(value.data.as_ptr(), res.data.as_mut_ptr(), LEN) };
+        res.len = value.len;
+        std::mem::forget(value);
+        res
+    }
+}
+impl<const LEN: usize, T, P> From<StackDeque<LEN, T>> for StackVec<LEN, T, P> {
+    /// Takes over the elements of a [`StackDeque`], making them contiguous first.
+    #[inline]
+    fn from(mut value: StackDeque<LEN, T>) -> Self {
+        value.make_contiguous();
+        let mut res: Self = Self::new();
+        // SAFETY: The elements are now in the first `value.len` slots, so we can take them over together with the length. We forget the deque below so it doesn't drop them.
+        unsafe { std::ptr::copy_nonoverlapping(value.data.as_ptr(), res.data.as_mut_ptr(), LEN) };
+        res.len = value.len;
+        std::mem::forget(value);
+        res
+    }
+}
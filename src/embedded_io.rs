@@ -0,0 +1,53 @@
+//  Description:
+//!   Implements [`embedded_io`]'s traits for byte [`StackVec`]s (as
+//!   writers) and [`ByteCursor`]s (as readers), available under the
+//!   `embedded-io` feature.
+//
+
+use std::convert::Infallible;
+
+use embedded_io::{BufRead, Error, ErrorKind, ErrorType, Read, Write};
+
+use crate::{ByteCursor, CapacityError, StackVec};
+
+
+/***** LIBRARY *****/
+// Errors
+impl<T> Error for CapacityError<T> {
+    #[inline]
+    fn kind(&self) -> ErrorKind { ErrorKind::OutOfMemory }
+}
+
+// Writing
+impl<const LEN: usize, P> ErrorType for StackVec<LEN, u8, P> {
+    type Error = CapacityError;
+}
+impl<const LEN: usize, P> Write for StackVec<LEN, u8, P> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        // `embedded_io` doesn't allow us to write nothing, so we error instead
+        if !buf.is_empty() && self.is_full() {
+            return Err(CapacityError::with_excess((), LEN, buf.len()));
+        }
+        Ok(self.write_truncated(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+}
+
+// Reading
+impl<const LEN: usize, P> ErrorType for ByteCursor<LEN, P> {
+    type Error = Infallible;
+}
+impl<const LEN: usize, P> Read for ByteCursor<LEN, P> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> { Ok(self.read_truncated(buf)) }
+}
+impl<const LEN: usize, P> BufRead for ByteCursor<LEN, P> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> { Ok(self.remaining_slice()) }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) { ByteCursor::consume(self, amt) }
+}
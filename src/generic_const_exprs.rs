@@ -0,0 +1,77 @@
+//  Description:
+//!   Implements concatenating and splitting [`StackVec`]s with capacities
+//!   computed at compile time, available under the (nightly-only)
+//!   `generic-const-exprs` feature.
+//!
+//!   Unlike going through e.g. [`StackVec::try_extend()`], these never need
+//!   to check the capacity at runtime, because the resulting capacities are
+//!   large enough by construction.
+//
+
+use crate::{LenType, StackVec};
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Concatenates this StackVec with another one, into a StackVec that has room for both.
+    ///
+    /// The elements are moved with two memory copies, without checking any capacities at runtime. Note that this shadows [`<[T]>::concat()`](slice::concat()); call it via [`Self::as_slice()`](StackVec::as_slice()) if you need that instead.
+    ///
+    /// # Arguments
+    /// - `other`: The StackVec (of any capacity, policy or length type) whose elements to append after ours.
+    ///
+    /// # Returns
+    /// A new StackVec of capacity `LEN + LEN2` with our elements, followed by those of `other`.
+    #[inline]
+    pub fn concat<const LEN2: usize, P2, L2: LenType>(mut self, mut other: StackVec<LEN2, T, P2, L2>) -> StackVec<{ LEN + LEN2 }, T, P, L>
+    where
+        [(); LEN + LEN2]:,
+    {
+        let (len, len2): (usize, usize) = (self.len(), other.len());
+        let mut res: StackVec<{ LEN + LEN2 }, T, P, L> = StackVec::new();
+        // SAFETY: The buffers are distinct, the first `len` and `len2` elements of the sources are initialized (as per their assertions), and `res` has room for `LEN + LEN2` elements.
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.as_ptr(), res.as_mut_ptr(), len);
+            std::ptr::copy_nonoverlapping(other.as_ptr(), res.as_mut_ptr().add(len), len2);
+        }
+        // SAFETY: The elements have been moved to `res`, so the sources must forget about them to not drop them twice.
+        self.len = L::ZERO;
+        other.len = L2::ZERO;
+        // SAFETY: We just initialized the first `len + len2` elements of `res`.
+        res.len = L::from_usize(len + len2);
+        res
+    }
+
+    /// Splits this StackVec in two at the given capacity.
+    ///
+    /// The first `K` elements are moved into the first StackVec, and any remaining ones into the second. If there are fewer than `K` elements, the second one is empty. Refuses to compile if `K` exceeds `LEN`.
+    ///
+    /// Note that this shadows [`<[T]>::split()`](slice::split()); call it via [`Self::as_slice()`](StackVec::as_slice()) if you need that instead.
+    ///
+    /// # Generics
+    /// - `K`: The capacity of the first StackVec, and thus the index at which to split.
+    ///
+    /// # Returns
+    /// A tuple of a StackVec of capacity `K` with the elements before index `K`, and a StackVec of capacity `LEN - K` with the elements after it.
+    #[inline]
+    pub fn split<const K: usize>(mut self) -> (StackVec<K, T, P, L>, StackVec<{ LEN - K }, T, P, L>)
+    where
+        [(); LEN - K]:,
+    {
+        let len: usize = self.len();
+        let (head, tail): (usize, usize) = if len > K { (K, len - K) } else { (len, 0) };
+        let mut first: StackVec<K, T, P, L> = StackVec::new();
+        let mut second: StackVec<{ LEN - K }, T, P, L> = StackVec::new();
+        // SAFETY: The buffers are distinct, the first `head + tail == len` elements of ours are initialized (as per our assertion), and `first` and `second` have room for `head <= K` and `tail <= LEN - K` elements, respectively.
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.as_ptr(), first.as_mut_ptr(), head);
+            std::ptr::copy_nonoverlapping(self.as_ptr().add(head), second.as_mut_ptr(), tail);
+        }
+        // SAFETY: The elements have been moved out, so we must forget about them to not drop them twice.
+        self.len = L::ZERO;
+        // SAFETY: We just initialized the first `head` and `tail` elements of `first` and `second`, respectively.
+        first.len = L::from_usize(head);
+        second.len = L::from_usize(tail);
+        (first, second)
+    }
+}
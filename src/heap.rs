@@ -0,0 +1,244 @@
+//  Description:
+//!   Implements a [`StackHeap`], a fixed-capacity binary max-heap that
+//!   lives in the stack.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+
+use crate::{CapacityError, IntoIter, StackVec};
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-capacity priority queue that lives in the stack.
+///
+/// Like a [`BinaryHeap`](std::collections::BinaryHeap), it is a max-heap, so [`Self::pop()`](StackHeap::pop()) always returns the greatest element. Use [`Reverse`](std::cmp::Reverse) to make it a min-heap instead.
+#[derive(Clone)]
+pub struct StackHeap<const LEN: usize, T> {
+    /// The elements of the heap.
+    ///
+    /// We implement the StackHeap such that it upholds the following assertion: every element in `vec` is greater than or equal to its children (i.e., the elements at `2 * i + 1` and `2 * i + 2`).
+    vec: StackVec<LEN, T>,
+}
+impl<const LEN: usize, T> Default for StackHeap<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> StackHeap<LEN, T> {
+    /// Constructor for the StackHeap that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackHeap with no elements in it.
+    #[inline]
+    pub const fn new() -> Self { Self { vec: StackVec::new() } }
+
+    /// Returns the greatest element in the StackHeap.
+    ///
+    /// # Returns
+    /// A reference to the greatest element, or [`None`] if the StackHeap is empty.
+    #[inline]
+    pub const fn peek(&self) -> Option<&T> { self.vec.first() }
+
+    /// Removes all elements from the StackHeap.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Returns the elements in the StackHeap, in arbitrary order.
+    ///
+    /// # Returns
+    /// A slice of all elements, in the order they are stored in.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { self.vec.as_slice() }
+
+    /// Returns an iterator over the elements in the StackHeap, in arbitrary order.
+    ///
+    /// # Returns
+    /// An iterator yielding references to the elements, in the order they are stored in.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.vec.iter() }
+
+    /// Returns the elements of the StackHeap as a StackVec, in arbitrary order.
+    ///
+    /// # Returns
+    /// The StackVec that backs this StackHeap.
+    #[inline]
+    pub fn into_stackvec(self) -> StackVec<LEN, T> { self.vec }
+
+    /// Returns the number of elements in the StackHeap.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() }
+
+    /// Returns whether there are any elements in the StackHeap.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.vec.is_empty() }
+
+    /// Returns whether the StackHeap has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.vec.is_full() }
+
+    /// Returns the maximum number of elements the StackHeap can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<const LEN: usize, T: Ord> StackHeap<LEN, T> {
+    /// Moves the element at the given index up until its parent is at least as great.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to move.
+    #[inline]
+    fn sift_up(&mut self, mut idx: usize) {
+        let data: &mut [T] = self.vec.as_slice_mut();
+        while idx > 0 {
+            let parent: usize = (idx - 1) / 2;
+            if data[idx] <= data[parent] {
+                break;
+            }
+            data.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    /// Moves the element at the given index down until its children are at most as great.
+    ///
+    /// # Arguments
+    /// - `data`: The elements to sift in. Any elements beyond it are ignored.
+    /// - `idx`: The index of the element to move.
+    #[inline]
+    fn sift_down(data: &mut [T], mut idx: usize) {
+        loop {
+            let mut child: usize = 2 * idx + 1;
+            if child >= data.len() {
+                break;
+            }
+            if child + 1 < data.len() && data[child + 1] > data[child] {
+                child += 1;
+            }
+            if data[idx] >= data[child] {
+                break;
+            }
+            data.swap(idx, child);
+            idx = child;
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the StackHeap.
+    ///
+    /// This is O(log n).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function panics if the StackHeap is already full.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, elem: T) {
+        if self.try_push(elem).is_err() {
+            panic!("Cannot push element to full StackHeap of capacity {LEN}");
+        }
+    }
+
+    /// Pushes a new element to the StackHeap, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push()`](StackHeap::push()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackHeap is already full, a [`CapacityError`] is returned that carries the given `elem`. The heap is untouched in that case.
+    #[inline]
+    pub fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        self.vec.try_push(elem)?;
+        self.sift_up(self.vec.len() - 1);
+        Ok(())
+    }
+
+    /// Removes the greatest element from the StackHeap.
+    ///
+    /// This is O(log n).
+    ///
+    /// # Returns
+    /// The greatest element, or [`None`] if the StackHeap is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        // Move the last element to the top, then restore the heap from there
+        let mut elem: T = self.vec.pop()?;
+        if let Some(top) = self.vec.first_mut() {
+            std::mem::swap(&mut elem, top);
+            Self::sift_down(self.vec.as_slice_mut(), 0);
+        }
+        Some(elem)
+    }
+
+    /// Consumes the StackHeap and returns its elements in ascending order.
+    ///
+    /// This is O(n log n), and happens in-place.
+    ///
+    /// # Returns
+    /// A StackVec with all elements, sorted from least to greatest.
+    #[inline]
+    pub fn into_sorted_stackvec(self) -> StackVec<LEN, T> {
+        let mut vec: StackVec<LEN, T> = self.vec;
+        let data: &mut [T] = vec.as_slice_mut();
+        // Repeatedly move the greatest element to the end of the shrinking heap
+        for end in (1..data.len()).rev() {
+            data.swap(0, end);
+            Self::sift_down(&mut data[..end], 0);
+        }
+        vec
+    }
+}
+
+impl<const LEN: usize, T: Debug> Debug for StackHeap<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_slice(), f) }
+}
+
+// Iteration
+impl<const LEN: usize, T> IntoIterator for StackHeap<LEN, T> {
+    type Item = T;
+    type IntoIter = IntoIter<LEN, T>;
+
+    /// Iterates over the elements in arbitrary order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
+}
+impl<'h, const LEN: usize, T> IntoIterator for &'h StackHeap<LEN, T> {
+    type Item = &'h T;
+    type IntoIter = std::slice::Iter<'h, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+// Conversion
+impl<const LEN: usize, T: Ord, P> From<StackVec<LEN, T, P>> for StackHeap<LEN, T> {
+    /// Turns the elements of a [`StackVec`] into a heap in-place, in O(n).
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        let mut vec: StackVec<LEN, T> = value.with_policy();
+        let data: &mut [T] = vec.as_slice_mut();
+        for idx in (0..data.len() / 2).rev() {
+            Self::sift_down(data, idx);
+        }
+        Self { vec }
+    }
+}
+impl<const LEN: usize, T> From<StackHeap<LEN, T>> for StackVec<LEN, T> {
+    /// Returns the elements of the [`StackHeap`] in arbitrary order.
+    #[inline]
+    fn from(value: StackHeap<LEN, T>) -> Self { value.vec }
+}
@@ -0,0 +1,43 @@
+//  Description:
+//!   Implements conversions between [`StackVec`]s and [`heapless::Vec`]s,
+//!   available under the `heapless` feature.
+//!
+//!   Like [`From<[T; N]>`](StackVec::from()), these conversions only compile
+//!   if the target's capacity is at least the source's capacity. To convert
+//!   to a smaller target, use e.g. [`StackVec::try_from_iter()`] instead.
+//
+
+use std::mem::MaybeUninit;
+
+use crate::{StackVec, const_assert};
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, const LEN2: usize, T, P> From<heapless::Vec<T, LEN2>> for StackVec<LEN, T, P> {
+    #[inline]
+    fn from(value: heapless::Vec<T, LEN2>) -> Self {
+        // Refuse to compile for vecs that may not fit
+        const { const_assert(LEN2 <= LEN, "Cannot create a StackVec from a heapless::Vec with a larger capacity") };
+
+        let mut stack: Self = Self::new();
+        for elem in value {
+            stack.data[stack.len] = MaybeUninit::new(elem);
+            stack.len += 1;
+        }
+        stack
+    }
+}
+impl<const LEN: usize, const LEN2: usize, T, P> From<StackVec<LEN, T, P>> for heapless::Vec<T, LEN2> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        // Refuse to compile for vecs that may not fit
+        const { const_assert(LEN <= LEN2, "Cannot create a heapless::Vec from a StackVec with a larger capacity") };
+
+        let mut vec: Self = Self::new();
+        for elem in value {
+            // SAFETY: The StackVec has at most `LEN` elements, which we asserted fits in `LEN2`.
+            unsafe { vec.push_unchecked(elem) };
+        }
+        vec
+    }
+}
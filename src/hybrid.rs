@@ -0,0 +1,470 @@
+//  Description:
+//!   Implements a [`HybridVec`], which keeps its elements in a [`StackVec`]
+//!   until they don't fit anymore, and then moves them to the heap.
+//
+
+use std::borrow::{Borrow, BorrowMut};
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::ops::{Deref, DerefMut};
+
+use crate::{InsertError, IntoIter, StackVec};
+
+
+/***** HELPERS *****/
+/// Defines where a [`HybridVec`] keeps its elements.
+#[derive(Clone)]
+enum Storage<const LEN: usize, T> {
+    /// The elements are inline, in the stack.
+    Inline(StackVec<LEN, T>),
+    /// The elements have been spilled to the heap.
+    Heap(Vec<T>),
+}
+
+
+
+
+
+/***** ITERATORS *****/
+/// Iterates over a [`HybridVec`] by ownership.
+pub struct HybridIntoIter<const LEN: usize, T> {
+    /// The iterator over wherever the elements were stored.
+    iter: HybridIntoIterKind<LEN, T>,
+}
+/// Defines the iterator wrapped by a [`HybridIntoIter`].
+enum HybridIntoIterKind<const LEN: usize, T> {
+    /// Iterates over inline elements.
+    Inline(IntoIter<LEN, T>),
+    /// Iterates over spilled elements.
+    Heap(std::vec::IntoIter<T>),
+}
+impl<const LEN: usize, T> HybridIntoIter<LEN, T> {
+    /// Returns the elements that haven't been yielded yet as a slice.
+    ///
+    /// # Returns
+    /// A slice of the remaining elements, in order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.iter {
+            HybridIntoIterKind::Inline(iter) => iter.as_slice(),
+            HybridIntoIterKind::Heap(iter) => iter.as_slice(),
+        }
+    }
+}
+impl<const LEN: usize, T: Debug> Debug for HybridIntoIter<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_tuple("HybridIntoIter").field(&self.as_slice()).finish() }
+}
+impl<const LEN: usize, T> Iterator for HybridIntoIter<LEN, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.iter {
+            HybridIntoIterKind::Inline(iter) => iter.next(),
+            HybridIntoIterKind::Heap(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len: usize = self.as_slice().len();
+        (len, Some(len))
+    }
+}
+impl<const LEN: usize, T> DoubleEndedIterator for HybridIntoIter<LEN, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.iter {
+            HybridIntoIterKind::Inline(iter) => iter.next_back(),
+            HybridIntoIterKind::Heap(iter) => iter.next_back(),
+        }
+    }
+}
+impl<const LEN: usize, T> ExactSizeIterator for HybridIntoIter<LEN, T> {}
+impl<const LEN: usize, T> FusedIterator for HybridIntoIter<LEN, T> {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a vector that keeps up to `LEN` elements inline, in a [`StackVec`], and moves them to a heap-allocated [`Vec`] once more are pushed.
+///
+/// This gives the cheap, allocation-free path for the common case, while still accepting the occasional large input instead of panicking. Use [`Self::spilled()`](HybridVec::spilled()) to find out which case you're in. Once spilled, the elements stay on the heap (until [`Self::shrink_to_fit()`](HybridVec::shrink_to_fit()) is called).
+#[derive(Clone)]
+pub struct HybridVec<const LEN: usize, T> {
+    /// Where the elements are stored.
+    storage: Storage<LEN, T>,
+}
+impl<const LEN: usize, T> Default for HybridVec<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> HybridVec<LEN, T> {
+    /// Constructor for the HybridVec that initializes it as empty, with its elements inline.
+    ///
+    /// # Returns
+    /// A new HybridVec with no elements in it.
+    #[inline]
+    pub const fn new() -> Self { Self { storage: Storage::Inline(StackVec::new()) } }
+
+    /// Moves the elements to the heap, if they aren't there already.
+    ///
+    /// # Arguments
+    /// - `additional`: The number of elements to reserve space for on top of the current ones.
+    ///
+    /// # Returns
+    /// The [`Vec`] that now holds the elements.
+    #[inline]
+    fn spill(&mut self, additional: usize) -> &mut Vec<T> {
+        if let Storage::Inline(stack) = &mut self.storage {
+            // Leave room to grow, so we don't reallocate on the very next push
+            let mut vec: Vec<T> = Vec::with_capacity((stack.len() + additional).max(2 * LEN));
+            vec.extend(std::mem::take(stack));
+            self.storage = Storage::Heap(vec);
+        }
+        match &mut self.storage {
+            Storage::Heap(vec) => {
+                vec.reserve(additional);
+                vec
+            },
+            // NOTE: We just made sure it's on the heap
+            Storage::Inline(_) => unreachable!(),
+        }
+    }
+
+    /// Returns whether the elements have been moved to the heap.
+    ///
+    /// # Returns
+    /// True if the HybridVec has spilled, or false if its elements are still inline.
+    #[inline]
+    pub const fn spilled(&self) -> bool { matches!(self.storage, Storage::Heap(_)) }
+
+    /// Pushes a new element to the end of the HybridVec, moving the elements to the heap if they don't fit inline anymore.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    #[inline]
+    pub fn push(&mut self, elem: T) {
+        match &mut self.storage {
+            Storage::Inline(stack) => {
+                if let Err(elem) = stack.push_within_capacity(elem) {
+                    self.spill(1).push(elem);
+                }
+            },
+            Storage::Heap(vec) => vec.push(elem),
+        }
+    }
+
+    /// Removes the last element from the HybridVec.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the HybridVec was empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline(stack) => stack.pop(),
+            Storage::Heap(vec) => vec.pop(),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element at the given position, moving the elements to the heap if they don't fit inline anymore.
+    ///
+    /// # Arguments
+    /// - `idx`: The position to insert the element at. Elements at and after it are moved one place back.
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Panics
+    /// This function panics if `idx` is greater than the length of the HybridVec.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, idx: usize, elem: T) {
+        if self.try_insert(idx, elem).is_err() {
+            panic!("Index {} is out-of-bounds for HybridVec of length {}", idx, self.len());
+        }
+    }
+
+    /// Inserts a new element at the given position, moving the elements to the heap if they don't fit inline anymore, or returns an error if the position is out-of-bounds.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](HybridVec::insert()).
+    ///
+    /// # Arguments
+    /// - `idx`: The position to insert the element at. Elements at and after it are moved one place back.
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Errors
+    /// If `idx` is greater than the length of the HybridVec, an [`InsertError::OutOfBounds`] is returned that carries the given `elem`. The HybridVec is untouched in that case.
+    #[inline]
+    pub fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), InsertError<T>> {
+        if idx > self.len() {
+            return Err(InsertError::OutOfBounds { idx, len: self.len(), elem });
+        }
+        match &mut self.storage {
+            // NOTE: Can't fail, as we checked both the index and that there is room
+            Storage::Inline(stack) if !stack.is_full() => stack.try_insert(idx, elem),
+            _ => {
+                self.spill(1).insert(idx, elem);
+                Ok(())
+            },
+        }
+    }
+
+    /// Removes an element from the HybridVec.
+    ///
+    /// This preserves the order of the other elements.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to remove.
+    ///
+    /// # Returns
+    /// The removed element, or else [`None`] if the `idx` is out-of-bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline(stack) => stack.remove(idx),
+            Storage::Heap(vec) => {
+                if idx < vec.len() {
+                    Some(vec.remove(idx))
+                } else {
+                    None
+                }
+            },
+        }
+    }
+
+    /// Shortens the HybridVec to the given length.
+    ///
+    /// Does nothing if the HybridVec is already shorter. This does not move the elements back inline.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the HybridVec.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.storage {
+            Storage::Inline(stack) => stack.truncate(len),
+            Storage::Heap(vec) => vec.truncate(len),
+        }
+    }
+
+    /// Removes all elements from the HybridVec.
+    ///
+    /// This does not move the elements back inline.
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+
+    /// Moves the elements back inline if they fit, or else shrinks the heap allocation to fit them.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        if let Storage::Heap(vec) = &mut self.storage {
+            match StackVec::try_from(std::mem::take(vec)) {
+                Ok(stack) => self.storage = Storage::Inline(stack),
+                Err(err) => {
+                    let mut vec: Vec<T> = err.into_element();
+                    vec.shrink_to_fit();
+                    self.storage = Storage::Heap(vec);
+                },
+            }
+        }
+    }
+
+    /// Returns the elements in the HybridVec.
+    ///
+    /// # Returns
+    /// A slice of all elements, wherever they are stored.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline(stack) => stack.as_slice(),
+            Storage::Heap(vec) => vec.as_slice(),
+        }
+    }
+
+    /// Returns the elements in the HybridVec, mutably.
+    ///
+    /// # Returns
+    /// A mutable slice of all elements, wherever they are stored.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline(stack) => stack.as_slice_mut(),
+            Storage::Heap(vec) => vec.as_mut_slice(),
+        }
+    }
+
+    /// Returns the elements of the HybridVec as a heap-allocated [`Vec`].
+    ///
+    /// # Returns
+    /// A [`Vec`] with all elements. Doesn't reallocate if the HybridVec already spilled.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        match self.storage {
+            Storage::Inline(stack) => stack.into_vec(),
+            Storage::Heap(vec) => vec,
+        }
+    }
+
+    /// Returns the number of elements in the HybridVec.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub fn len(&self) -> usize { self.as_slice().len() }
+
+    /// Returns whether there are any elements in the HybridVec.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the HybridVec can hold without (re)allocating.
+    ///
+    /// # Returns
+    /// `LEN` if the elements are inline, or else the capacity of the heap allocation.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(_) => LEN,
+            Storage::Heap(vec) => vec.capacity(),
+        }
+    }
+}
+impl<const LEN: usize, T: Clone> HybridVec<LEN, T> {
+    /// Appends clones of all elements in the given slice to the end of the HybridVec, moving the elements to the heap if they don't all fit inline.
+    ///
+    /// # Arguments
+    /// - `elems`: The elements to append.
+    #[inline]
+    pub fn extend_from_slice(&mut self, elems: &[T]) {
+        match &mut self.storage {
+            Storage::Inline(stack) if elems.len() <= stack.remaining_capacity() => {
+                // NOTE: Can't fail, as we just checked they fit
+                let _ = stack.try_extend_from_slice(elems);
+            },
+            _ => self.spill(elems.len()).extend_from_slice(elems),
+        }
+    }
+}
+
+impl<const LEN: usize, T: Debug> Debug for HybridVec<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_slice(), f) }
+}
+impl<const LEN: usize, T: Eq> Eq for HybridVec<LEN, T> {}
+impl<const LEN: usize, T: Hash> Hash for HybridVec<LEN, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<HybridVec<LEN2, T>> for HybridVec<LEN, T> {
+    /// Compares the elements, regardless of whether either HybridVec spilled.
+    #[inline]
+    fn eq(&self, other: &HybridVec<LEN2, T>) -> bool { self.as_slice() == other.as_slice() }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<[T; LEN2]> for HybridVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T; LEN2]) -> bool { self.as_slice() == other }
+}
+impl<const LEN: usize, T: PartialEq> PartialEq<[T]> for HybridVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+// Deref
+impl<const LEN: usize, T> Deref for HybridVec<LEN, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+impl<const LEN: usize, T> DerefMut for HybridVec<LEN, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_slice() }
+}
+
+// Borrowing
+impl<const LEN: usize, T> AsRef<[T]> for HybridVec<LEN, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+impl<const LEN: usize, T> AsMut<[T]> for HybridVec<LEN, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+impl<const LEN: usize, T> Borrow<[T]> for HybridVec<LEN, T> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self.as_slice() }
+}
+impl<const LEN: usize, T> BorrowMut<[T]> for HybridVec<LEN, T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
+// Iteration
+impl<const LEN: usize, T> IntoIterator for HybridVec<LEN, T> {
+    type Item = T;
+    type IntoIter = HybridIntoIter<LEN, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        HybridIntoIter {
+            iter: match self.storage {
+                Storage::Inline(stack) => HybridIntoIterKind::Inline(stack.into_iter()),
+                Storage::Heap(vec) => HybridIntoIterKind::Heap(vec.into_iter()),
+            },
+        }
+    }
+}
+impl<'h, const LEN: usize, T> IntoIterator for &'h HybridVec<LEN, T> {
+    type Item = &'h T;
+    type IntoIter = std::slice::Iter<'h, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_slice().iter() }
+}
+impl<'h, const LEN: usize, T> IntoIterator for &'h mut HybridVec<LEN, T> {
+    type Item = &'h mut T;
+    type IntoIter = std::slice::IterMut<'h, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_mut_slice().iter_mut() }
+}
+
+// From
+impl<const LEN: usize, T> FromIterator<T> for HybridVec<LEN, T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut res: Self = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+impl<const LEN: usize, T> Extend<T> for HybridVec<LEN, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        // Spill up front if we know the elements won't fit
+        let (lower, _): (usize, Option<usize>) = iter.size_hint();
+        if self.len() + lower > LEN {
+            self.spill(lower);
+        }
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+impl<const LEN: usize, T, P> From<StackVec<LEN, T, P>> for HybridVec<LEN, T> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self { Self { storage: Storage::Inline(value.with_policy()) } }
+}
+impl<const LEN: usize, T> From<Vec<T>> for HybridVec<LEN, T> {
+    /// Takes over the [`Vec`]'s allocation, so the result is always spilled.
+    #[inline]
+    fn from(value: Vec<T>) -> Self { Self { storage: Storage::Heap(value) } }
+}
+impl<const LEN: usize, T> From<HybridVec<LEN, T>> for Vec<T> {
+    #[inline]
+    fn from(value: HybridVec<LEN, T>) -> Self { value.into_vec() }
+}
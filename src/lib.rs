@@ -3,735 +3,4880 @@
 //!   structure that lives in the stack.
 //
 
+// Enable nightly features
+#![cfg_attr(feature = "generic-const-exprs", allow(incomplete_features))]
+#![cfg_attr(feature = "generic-const-exprs", feature(generic_const_exprs))]
+
+// Declare modules
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod c_string;
+mod contiguous;
+mod copy;
+mod cursor;
+mod deque;
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+#[cfg(feature = "generic-const-exprs")]
+mod generic_const_exprs;
+mod heap;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod hybrid;
+mod map;
+mod matrix;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+mod set;
+mod slab;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+mod sorted;
+mod spsc;
+mod string;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+
+// Re-exports
+pub use crate::c_string::{CStringError, StackCString};
+pub use crate::contiguous::ContiguousVec;
+pub use crate::copy::CopyStackVec;
+pub use crate::cursor::ByteCursor;
+pub use crate::deque::{DequeIntoIter, DequeIter, DequeIterMut, StackDeque};
+pub use crate::heap::StackHeap;
+pub use crate::hybrid::{HybridIntoIter, HybridVec};
+pub use crate::map::{MapEntry, MapIter, MapIterMut, OccupiedMapEntry, StackMap, VacantMapEntry};
+pub use crate::matrix::StackMatrix;
+pub use crate::set::StackSet;
+pub use crate::slab::StackSlab;
+pub use crate::sorted::SortedStackVec;
+pub use crate::spsc::{Consumer, Producer, SpscQueue};
+pub use crate::string::StackString;
+#[cfg(feature = "rkyv")]
+pub use crate::rkyv::ArchivedStackVec;
+
+// Imports
+use std::borrow::{Borrow, BorrowMut};
 use std::cmp::Ordering;
-use std::fmt::{Debug, Formatter, Result as FResult};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Arguments, Debug, Display, Error as FError, Formatter, Result as FResult, Write as FWrite};
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
 use std::iter::FusedIterator;
-use std::mem::MaybeUninit;
-use std::ops::{Deref, DerefMut, Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::marker::PhantomData;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ops::{Bound, Deref, DerefMut, Index, IndexMut, Range, RangeBounds, RangeFull};
+#[cfg(not(feature = "no-panic"))]
+use std::ops::{RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::slice::SliceIndex;
+
+
+/***** TESTS *****/
+#[cfg(test)]
+pub mod tests {
+    #[cfg(not(feature = "no-panic"))]
+    mod panicking {
+        use std::collections::VecDeque;
+        use std::mem::MaybeUninit;
+
+        use crate::{
+            ByteCursor, CStringError, CapacityError, ContiguousVec, CopyStackVec, DropOnFull, ErrorOnFull, HybridVec, OverwriteOldest, PanicOnFull, SortedStackVec, StackCString, StackDeque, SpscQueue, StackHeap,
+            StackMap, StackMatrix, StackSet, StackSlab, StackString, StackVec,
+        };
+
+        #[test]
+        fn extend() {
+            // Build a vec
+            let vec: StackVec<3, String> = StackVec::from(["D".into(), "E".into(), "F".into()]);
+            // Build another vec that extends
+            let mut vec2: StackVec<6, String> = StackVec::from(["A".into(), "B".into(), "C".into()]);
+            vec2.extend(vec);
+            assert_eq!(vec2, ["A".into(), "B".into(), "C".into(), "D".into(), "E".into(), "F".into()]);
+        }
+
+        #[test]
+        fn extend_trait() {
+            fn extend_with_refs<'a>(target: &mut impl Extend<&'a u8>, elems: &'a [u8]) { target.extend(elems) }
+
+            let mut vec: StackVec<5, u8> = StackVec::from([1]);
+            extend_with_refs(&mut vec, &[2, 3]);
+            Extend::<u8>::extend(&mut vec, [4, 5]);
+            assert_eq!(vec, [1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn extend_empty() {
+            // Extend an empty vec
+            let mut vec3: StackVec<3, String> = StackVec::from(["A".into(), "B".into(), "C".into()]);
+            vec3.extend(StackVec::<3, String>::new());
+            assert_eq!(vec3, ["A".into(), "B".into(), "C".into()]);
+        }
+
+        #[test]
+        fn extend_vec() {
+            // Extend a vec of 1 into a vector
+            let mut vec4: Vec<String> = Vec::with_capacity(5);
+            vec4.extend(StackVec::<5, String>::from(["Hello there!".into(), "General Kenobi!".into()]));
+            assert_eq!(vec4, vec!["Hello there!".to_string(), "General Kenobi!".to_string()]);
+        }
+
+        #[test]
+        fn extend_vec_empty() {
+            // Extend an empty vec into a vector
+            let mut vec4: Vec<String> = Vec::with_capacity(5);
+            vec4.extend(StackVec::<5, String>::new());
+            assert_eq!(vec4, Vec::<String>::new());
+        }
+
+        #[test]
+        fn sort() {
+            // Do one with a from
+            let mut vec: StackVec<5, &'static str> = StackVec::from(["Dan", "Amy", "Cho", "Eve", "Bob"]);
+            vec.sort();
+            assert_eq!(vec, ["Amy", "Bob", "Cho", "Dan", "Eve"]);
+        }
+
+        #[test]
+        fn extract_if() {
+            // Extract the even numbers only
+            let mut vec: StackVec<6, u32> = StackVec::from([1, 2, 3, 4, 5, 6]);
+            let evens: Vec<u32> = vec.extract_if(|elem| *elem % 2 == 0).collect();
+            assert_eq!(evens, vec![2, 4, 6]);
+            assert_eq!(vec, [1, 3, 5]);
+
+            // Stop halfway; the rest should be kept
+            let mut vec: StackVec<5, String> = StackVec::from(["A".into(), "B".into(), "C".into(), "D".into(), "E".into()]);
+            assert_eq!(vec.extract_if(|_| true).next(), Some("A".into()));
+            assert_eq!(vec, ["B".into(), "C".into(), "D".into(), "E".into()]);
+        }
+
+        #[cfg(feature = "embedded-io")]
+        #[test]
+        fn embedded_io() {
+            use embedded_io::{BufRead, Error, ErrorKind, Read, Write};
+
+            let mut vec: StackVec<4, u8> = StackVec::new();
+            assert_eq!(Write::write(&mut vec, b"abc"), Ok(3));
+            assert_eq!(Write::write(&mut vec, b"de"), Ok(1));
+            assert_eq!(Write::write(&mut vec, b"").unwrap(), 0);
+            assert_eq!(Write::write(&mut vec, b"e").unwrap_err().kind(), ErrorKind::OutOfMemory);
+            assert!(Write::write_all(&mut vec, b"e").is_err());
+
+            let mut cursor: ByteCursor<4> = ByteCursor::new(vec);
+            let mut buf: [u8; 3] = [0; 3];
+            assert_eq!(Read::read(&mut cursor, &mut buf), Ok(3));
+            assert_eq!(&buf, b"abc");
+            assert_eq!(BufRead::fill_buf(&mut cursor), Ok(&b"d"[..]));
+            BufRead::consume(&mut cursor, 1);
+            assert_eq!(Read::read(&mut cursor, &mut buf), Ok(0));
+        }
+
+        #[test]
+        fn find_byte() {
+            let vec: StackVec<16, u8> = StackVec::from(*b"GET /\r\nA: b\r\n");
+            assert!(vec.contains_byte(b':'));
+            assert!(!vec.contains_byte(0));
+            assert_eq!(vec.find_byte(b'\r'), Some(5));
+            assert_eq!(vec.rfind_byte(b'\r'), Some(11));
+            assert_eq!(vec.find_byte(b'x'), None);
+            assert_eq!(vec.find_bytes(b"\r\n"), Some(5));
+            assert_eq!(vec.find_bytes(b"\r\n\r\n"), None);
+            assert_eq!(vec.find_bytes(b""), Some(0));
+        }
+
+        #[test]
+        fn fmt_write() {
+            use std::fmt::{Error, Write};
+
+            let mut vec: StackVec<9, u8> = StackVec::new();
+            write!(vec, "T={:.1}", 21.34).unwrap();
+            assert_eq!(vec, *b"T=21.3");
+
+            // Fails without writing when it doesn't fit
+            assert_eq!(vec.write_str("°C"), Ok(()));
+            assert_eq!(vec.write_str("!"), Err(Error));
+            assert_eq!(vec, *"T=21.3°C".as_bytes());
+
+            // Also when only a later argument doesn't fit
+            let mut vec: StackVec<4, u8> = StackVec::new();
+            let (a, b): (&str, &str) = ("ab", "cde");
+            assert_eq!(write!(vec, "{a}{b}"), Err(Error));
+            assert!(vec.is_empty());
+        }
+
+        #[test]
+        fn io_write() {
+            use std::io::{ErrorKind, Write};
+
+            let mut vec: StackVec<8, u8> = StackVec::new();
+            write!(vec, "{}-{}", 12, 34).unwrap();
+            assert_eq!(vec, *b"12-34");
+
+            // Writes are cut short when full
+            assert_eq!(vec.write(b"5678").unwrap(), 3);
+            assert_eq!(vec, *b"12-34567");
+            assert_eq!(vec.write(b"8").unwrap(), 0);
+            assert_eq!(vec.write_all(b"8").unwrap_err().kind(), ErrorKind::WriteZero);
+        }
+
+        #[cfg(feature = "bytes")]
+        #[test]
+        fn bytes() {
+            use bytes::{Buf, BufMut};
+
+            let mut vec: StackVec<8, u8> = StackVec::new();
+            vec.put_u16(0x0102);
+            vec.put_slice(b"abc");
+            assert_eq!(vec.remaining_mut(), 3);
+            assert_eq!(vec, *b"\x01\x02abc");
+
+            let mut cursor: ByteCursor<8> = ByteCursor::new(vec);
+            assert_eq!(cursor.get_u16(), 0x0102);
+            assert_eq!(Buf::remaining(&cursor), 3);
+            assert_eq!(cursor.copy_to_bytes(2), &b"ab"[..]);
+            assert_eq!(cursor.chunk(), b"c");
+        }
+
+        #[test]
+        fn byte_cursor() {
+            use std::io::{BufRead, Read};
+
+            let mut cursor: ByteCursor<8> = ByteCursor::new(StackVec::from(*b"ab\ncdef"));
+            let mut line: String = String::new();
+            cursor.read_line(&mut line).unwrap();
+            assert_eq!(line, "ab\n");
+            assert_eq!(cursor.position(), 3);
+            assert_eq!(cursor.remaining(), 4);
+
+            cursor.consume(1);
+            let mut buf: [u8; 8] = [0; 8];
+            assert_eq!(cursor.read(&mut buf[..2]).unwrap(), 2);
+            assert_eq!(&buf[..2], b"de");
+            assert_eq!(cursor.read(&mut buf).unwrap(), 1);
+            assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+            cursor.consume(10);
+            assert_eq!(cursor.remaining_slice(), b"");
+            assert_eq!(cursor.into_inner().len(), 7);
+        }
+
+        #[test]
+        fn stack_c_string() {
+            use std::ffi::CStr;
+
+            let mut s: StackCString<6> = StackCString::try_from("abc").unwrap();
+            assert_eq!(s.as_c_str(), c"abc");
+            assert_eq!(s.as_bytes_with_nul(), b"abc\0");
+            assert_eq!(s.capacity(), 5);
+
+            // Pushing keeps the terminator, and never overwrites it
+            s.push_str("de").unwrap();
+            assert_eq!(s.to_str(), Ok("abcde"));
+            assert!(matches!(s.push(b'f'), Err(CStringError::Capacity(_))));
+            assert_eq!(s.push_str("a\0b"), Err(CStringError::InteriorNul { pos: 1 }));
+            assert_eq!(s.as_c_str(), c"abcde");
+
+            // Shrinking moves the terminator along
+            assert_eq!(s.pop(), Some(b'e'));
+            s.truncate(2);
+            assert_eq!(s.as_bytes_with_nul(), b"ab\0");
+            s.clear();
+            assert!(s.is_empty());
+            assert_eq!(unsafe { CStr::from_ptr(s.as_ptr()) }, c"");
+            assert_eq!(s.pop(), None);
+
+            assert!(StackCString::<3>::try_from("abc").is_err());
+            assert!(StackCString::<3>::try_from(c"abc").is_err());
+            assert_eq!(StackCString::<4>::try_from(c"abc").unwrap().as_c_str(), c"abc");
+        }
+
+        #[test]
+        fn stack_deque() {
+            let mut d: StackDeque<4, i32> = StackDeque::new();
+            assert_eq!(d.pop_front(), None);
+            d.push_back(2);
+            d.push_back(3);
+            d.push_front(1);
+            d.push_front(0);
+            assert_eq!(d, [0, 1, 2, 3]);
+            assert_eq!((d.front(), d.back()), (Some(&0), Some(&3)));
+
+            // Wrapping around splits the elements over two slices
+            assert_eq!(d.as_slices(), ([0, 1].as_slice(), [2, 3].as_slice()));
+            assert_eq!(d.pop_back(), Some(3));
+            assert_eq!(d.pop_back(), Some(2));
+            d.push_back(4);
+            d.push_back(5);
+            assert_eq!(d.as_slices(), ([0, 1].as_slice(), [4, 5].as_slice()));
+            assert_eq!(d[3], 5);
+            assert_eq!(d.iter().rev().copied().collect::<Vec<i32>>(), [5, 4, 1, 0]);
+            for elem in &mut d {
+                *elem *= 10;
+            }
+            assert_eq!(d.make_contiguous(), [0, 10, 40, 50]);
+            assert!(d.as_slices().1.is_empty());
+
+            // Conversions move the elements without cloning
+            assert_eq!(d.pop_back(), Some(50));
+            let v: StackVec<4, i32> = d.clone().into();
+            assert_eq!(v, [0, 10, 40]);
+            assert_eq!(StackDeque::from(v), d);
+            assert_eq!(d.into_iter().rev().collect::<Vec<i32>>(), [40, 10, 0]);
+
+            // Only the remaining elements are dropped, once
+            let rc: std::rc::Rc<()> = std::rc::Rc::new(());
+            let mut d: StackDeque<3, std::rc::Rc<()>> = StackDeque::new();
+            for _ in 0..3 {
+                d.push_front(rc.clone());
+            }
+            d.pop_back();
+            d.push_back(rc.clone());
+            assert_eq!(std::rc::Rc::strong_count(&rc), 4);
+            drop(d);
+            assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+        }
+
+        #[test]
+        fn stack_heap() {
+            use std::cmp::Reverse;
+
+            let mut h: StackHeap<5, i32> = StackHeap::new();
+            assert_eq!(h.pop(), None);
+            for elem in [3, 1, 4, 1, 5] {
+                h.push(elem);
+            }
+            assert!(h.try_push(9).is_err());
+            assert_eq!(h.peek(), Some(&5));
+            assert_eq!(h.pop(), Some(5));
+            assert_eq!(h.pop(), Some(4));
+            h.push(2);
+            assert_eq!(h.len(), 4);
+            assert_eq!(h.into_sorted_stackvec(), [1, 1, 2, 3]);
+
+            // Heapifying a StackVec, and using it as a min-heap
+            let vec: StackVec<8, Reverse<i32>> = [5, 8, 2, 7, 1, 9].into_iter().map(Reverse).collect();
+            let mut h: StackHeap<8, Reverse<i32>> = StackHeap::from(vec);
+            let mut popped: Vec<i32> = Vec::new();
+            while let Some(Reverse(elem)) = h.pop() {
+                popped.push(elem);
+            }
+            assert_eq!(popped, [1, 2, 5, 7, 8, 9]);
+            assert_eq!(StackHeap::<3, i32>::from(StackVec::<3, i32>::from([1, 3, 2])).into_sorted_stackvec(), [1, 2, 3]);
+        }
+
+        #[test]
+        fn stack_map() {
+            let mut m: StackMap<3, String, i32> = StackMap::new();
+            assert_eq!(m.insert("b".into(), 1), None);
+            assert_eq!(m.insert("a".into(), 2), None);
+            assert_eq!(m.insert("b".into(), 3), Some(1));
+            assert_eq!(m.len(), 2);
+            assert_eq!(m.get("b"), Some(&3));
+            assert_eq!(m["a"], 2);
+            assert!(!m.contains_key("c"));
+
+            // The entry API updates in-place, and only inserts when vacant
+            *m.entry("c".into()).or_insert(0) += 10;
+            m.entry("c".into()).and_modify(|v| *v += 1).or_insert(0);
+            assert_eq!(m.get("c"), Some(&11));
+            assert_eq!(m.try_insert("a".into(), 5), Ok(Some(2)));
+
+            // Iteration is in insertion order, also after removing
+            assert_eq!(m.keys().map(String::as_str).collect::<Vec<&str>>(), ["b", "a", "c"]);
+            assert_eq!(m.remove("b"), Some(3));
+            assert_eq!(m.remove("b"), None);
+            for (_, v) in &mut m {
+                *v *= 2;
+            }
+            assert_eq!(m.iter().map(|(k, v)| (k.as_str(), *v)).collect::<Vec<(&str, i32)>>(), [("a", 10), ("c", 22)]);
+            m.retain(|_, v| *v > 10);
+            assert_eq!(format!("{m:?}"), "{\"c\": 22}");
+
+            // Equality ignores the order
+            let lhs: StackMap<4, i32, i32> = StackMap::try_from([(1, 1), (2, 2)]).unwrap();
+            let rhs: StackMap<2, i32, i32> = StackMap::try_from([(2, 2), (1, 1), (1, 1)]).unwrap();
+            assert_eq!(lhs, rhs);
+            assert_eq!(StackMap::<1, i32, i32>::try_from([(1, 1), (2, 2), (3, 3)]).unwrap_err().excess(), 2);
+        }
+
+        #[test]
+        fn stack_matrix() {
+            let mut m: StackMatrix<2, 3, i32> = StackMatrix::from_fn(|row, col| (row * 3 + col) as i32);
+            assert_eq!(m.as_slice(), [0, 1, 2, 3, 4, 5]);
+            assert_eq!((m.num_rows(), m.num_columns()), (2, 3));
+            assert_eq!(m.get(1, 2), Some(&5));
+            assert_eq!(m.get(2, 0), None);
+            assert_eq!(m.get(0, 3), None);
+            assert_eq!(m[(1, 0)], 3);
+            m[(1, 0)] = 30;
+            assert_eq!(m.row(1), Some(&[30, 4, 5]));
+
+            // Iterating over rows and columns
+            assert_eq!(m.rows().map(|row| row.iter().sum()).collect::<Vec<i32>>(), [3, 39]);
+            assert_eq!(m.column(0).copied().collect::<Vec<i32>>(), [0, 30]);
+            assert_eq!(m.column(3).count(), 0);
+            for row in m.rows_mut() {
+                row.reverse();
+            }
+            assert_eq!(m, StackMatrix::from([[2, 1, 0], [5, 4, 30]]));
+
+            // Transposing, and it's `Copy` for `Copy` elements
+            let t: StackMatrix<3, 2, i32> = m.transpose();
+            assert_eq!(t.into_rows(), [[2, 5], [1, 4], [0, 30]]);
+            let copy: StackMatrix<2, 3, i32> = m;
+            assert_eq!(copy, m);
+            assert_eq!(format!("{:?}", StackMatrix::<2, 2, u8>::filled(7)), "[[7, 7], [7, 7]]");
+            assert!(std::panic::catch_unwind(|| StackMatrix::<2, 2, u8>::default()[(0, 2)]).is_err());
+        }
+
+        #[test]
+        fn stack_set() {
+            let mut s: StackSet<4, i32> = StackSet::new();
+            assert!(s.insert(3));
+            assert!(s.insert(1));
+            assert!(!s.insert(3));
+            assert!(s.insert(2));
+            assert_eq!(s.as_slice(), [3, 1, 2]);
+            assert!(s.contains(&1));
+            assert!(s.remove(&1));
+            assert!(!s.remove(&1));
+            assert_eq!(s.take(&2), Some(2));
+            s.insert(4);
+            s.insert(5);
+            s.insert(6);
+
+            // Set operations
+            let other: StackSet<3, i32> = StackSet::try_from([5, 8, 3, 8]).unwrap();
+            assert_eq!(s.union(&other).copied().collect::<Vec<i32>>(), [3, 4, 5, 6, 8]);
+            assert_eq!(s.intersection(&other).copied().collect::<Vec<i32>>(), [3, 5]);
+            assert_eq!(s.difference(&other).copied().collect::<Vec<i32>>(), [4, 6]);
+            assert!(!s.is_disjoint(&other));
+            s.retain(|elem| *elem != 4 && *elem != 6);
+            assert!(s.is_subset(&other));
+            assert_ne!(s, other);
+            assert_eq!(s, StackSet::<2, i32>::try_from([5, 3]).unwrap());
+            assert_eq!(format!("{s:?}"), "{3, 5}");
+        }
+
+        #[test]
+        #[should_panic]
+        fn contiguous_vec_insert() {
+            let mut stack: StackVec<1, u8> = StackVec::new();
+            ContiguousVec::insert(&mut stack, 0, 1);
+            ContiguousVec::insert(&mut stack, 0, 2);
+        }
+
+        #[test]
+        fn copy_stack_vec() {
+            use std::cell::Cell;
+
+            let mut v: CopyStackVec<4, u16> = CopyStackVec::new();
+            v.push(1);
+            v.extend_from_slice(&[2, 3]);
+            assert!(v.try_extend_from_slice(&[4, 5]).is_err());
+
+            // Copying leaves the original untouched
+            let mut w: CopyStackVec<4, u16> = v;
+            w[0] = 10;
+            assert_eq!(w.pop(), Some(3));
+            assert_eq!(v, [1, 2, 3]);
+            assert_eq!(w, [10, 2]);
+
+            // It can live in a `Cell`
+            let cell: Cell<CopyStackVec<4, u16>> = Cell::new(v);
+            let mut inner: CopyStackVec<4, u16> = cell.get();
+            assert!(inner.try_push(4).is_ok());
+            assert!(inner.try_push(5).is_err());
+            cell.set(inner);
+            assert_eq!(cell.get().len(), 4);
+
+            // Conversions to and from the StackVec
+            let stack: StackVec<4, u16> = cell.get().into();
+            assert_eq!(stack, [1, 2, 3, 4]);
+            assert_eq!(CopyStackVec::from(stack).into_iter().rev().collect::<Vec<u16>>(), [4, 3, 2, 1]);
+            assert_eq!(CopyStackVec::<2, u16>::try_from([7, 8].as_slice()).unwrap(), [7, 8]);
+        }
+
+        #[test]
+        fn hybrid_vec() {
+            let mut v: HybridVec<3, String> = HybridVec::new();
+            v.push("a".into());
+            v.push("b".into());
+            v.insert(0, "c".into());
+            assert!(!v.spilled());
+            assert_eq!(v.capacity(), 3);
+
+            // Overflowing moves everything to the heap, in order
+            v.push("d".into());
+            assert!(v.spilled());
+            assert!(v.capacity() >= 4);
+            assert_eq!(v, ["c", "a", "b", "d"].map(String::from));
+            v.insert(4, "e".into());
+            assert_eq!(v.remove(0).as_deref(), Some("c"));
+            assert_eq!(v.remove(9), None);
+            v.truncate(3);
+            assert_eq!(v.pop().as_deref(), Some("d"));
+
+            // ...until it's shrunk again
+            v.shrink_to_fit();
+            assert!(!v.spilled());
+            assert_eq!(v.iter().map(String::as_str).collect::<Vec<&str>>(), ["a", "b"]);
+            assert_eq!(v.clone().into_iter().rev().collect::<Vec<String>>(), ["b", "a"]);
+
+            let v: HybridVec<4, i32> = (0..3).collect();
+            assert!(!v.spilled());
+            let mut v: HybridVec<4, i32> = (0..10).collect();
+            assert!(v.spilled());
+            v.extend_from_slice(&[10, 11]);
+            assert_eq!(v.into_vec(), (0..12).collect::<Vec<i32>>());
+        }
+
+        #[test]
+        fn sorted_stack_vec() {
+            let mut v: SortedStackVec<6, i32> = SortedStackVec::new();
+            assert_eq!(v.insert(5), 0);
+            assert_eq!(v.insert(1), 0);
+            assert_eq!(v.insert(3), 1);
+            assert_eq!(v.insert(3), 2);
+            assert_eq!(v, [1, 3, 3, 5]);
+
+            // Deduplicating variants
+            assert_eq!(v.insert_unique(3), None);
+            assert_eq!(v.insert_unique(4), Some(3));
+            assert_eq!(v.replace(4), Ok(Some(4)));
+            assert!(v.try_insert(9).is_ok());
+            assert!(v.try_insert_unique(0).is_err());
+            assert!(v.replace(0).is_err());
+            assert_eq!(v, [1, 3, 3, 4, 5, 9]);
+
+            // Lookups
+            assert!(v.contains(&4));
+            assert!(!v.contains(&2));
+            assert_eq!(v.range(3..5), [3, 3, 4]);
+            assert_eq!(v.range(..=3), [1, 3, 3]);
+            assert_eq!(v.range((std::ops::Bound::Excluded(3), std::ops::Bound::Unbounded)), [4, 5, 9]);
+            assert!(v.range(6..9).is_empty());
+            assert_eq!(v.remove_item(&3), Some(3));
+            assert_eq!(v.remove_item(&7), None);
+            assert_eq!(v.pop(), Some(9));
+            assert_eq!(v.first(), Some(&1));
+
+            let v: SortedStackVec<4, &str> = StackVec::<4, &str>::from(["d", "a", "c", "b"]).into();
+            assert_eq!(v.into_stackvec(), ["a", "b", "c", "d"]);
+        }
+
+        #[test]
+        fn spsc_queue() {
+            use std::rc::Rc;
+
+            let mut q: SpscQueue<3, u32> = SpscQueue::new();
+            let (mut tx, mut rx) = q.split();
+            assert_eq!(rx.pop(), None);
+            for i in 0..3 {
+                tx.try_push(i).unwrap();
+            }
+            assert!(tx.is_full());
+            assert_eq!(tx.try_push(3).unwrap_err().into_element(), 3);
+            assert_eq!(rx.peek(), Some(&0));
+            assert_eq!(rx.pop(), Some(0));
+            tx.try_push(3).unwrap();
+            assert_eq!(std::iter::from_fn(|| rx.pop()).collect::<Vec<u32>>(), [1, 2, 3]);
+            assert!(rx.is_empty());
+
+            // Move elements across threads
+            let mut q: SpscQueue<4, u32> = SpscQueue::new();
+            let (mut tx, mut rx) = q.split();
+            let received: Vec<u32> = std::thread::scope(|s| {
+                s.spawn(move || {
+                    for i in 0..200 {
+                        while tx.try_push(i).is_err() {
+                            std::thread::yield_now();
+                        }
+                    }
+                });
+                let mut received: Vec<u32> = Vec::with_capacity(200);
+                while received.len() < 200 {
+                    match rx.pop() {
+                        Some(elem) => received.push(elem),
+                        None => std::thread::yield_now(),
+                    }
+                }
+                received
+            });
+            assert_eq!(received, (0..200).collect::<Vec<u32>>());
+
+            // A queue in a `static` can be split once, after which the handles can live anywhere
+            static QUEUE: SpscQueue<2, u32> = SpscQueue::new();
+            let (mut tx, mut rx) = QUEUE.split_static().unwrap();
+            assert!(QUEUE.split_static().is_none());
+            std::thread::spawn(move || tx.try_push(42).unwrap()).join().unwrap();
+            assert_eq!(rx.pop(), Some(42));
+
+            // Remaining elements are dropped with the queue
+            let rc: Rc<()> = Rc::new(());
+            let mut q: SpscQueue<2, Rc<()>> = SpscQueue::new();
+            q.split().0.try_push(rc.clone()).unwrap();
+            assert_eq!(q.len(), 1);
+            drop(q);
+            assert_eq!(Rc::strong_count(&rc), 1);
+        }
+
+        #[test]
+        fn stack_slab() {
+            let mut s: StackSlab<3, &str> = StackSlab::new();
+            assert_eq!(s.vacant_key(), Some(0));
+            let a: usize = s.insert("a");
+            let b: usize = s.insert("b");
+            let c: usize = s.insert("c");
+            assert_eq!((a, b, c), (0, 1, 2));
+            assert!(s.try_insert("d").is_err());
+            assert_eq!(s.vacant_key(), None);
+
+            // Removing keeps the other keys valid, and frees up the slot for reuse
+            assert_eq!(s.remove(b), Some("b"));
+            assert_eq!(s.remove(b), None);
+            assert_eq!(s.get(b), None);
+            assert_eq!((s[a], s[c]), ("a", "c"));
+            assert_eq!(s.remove(a), Some("a"));
+            assert_eq!(s.vacant_key(), Some(a));
+            assert_eq!(s.insert("e"), a);
+            assert_eq!(s.insert("f"), b);
+            assert!(s.is_full());
+
+            s[c] = "g";
+            s.retain(|key, _| key != a);
+            assert_eq!(s.iter().collect::<Vec<(usize, &&str)>>(), [(1, &"f"), (2, &"g")]);
+            assert_eq!(format!("{s:?}"), "{1: \"f\", 2: \"g\"}");
+            s.clear();
+            assert_eq!(s.insert("h"), 0);
+        }
+
+        #[test]
+        fn stack_string() {
+            use std::fmt::Write as _;
+
+            let mut s: StackString<8> = "héllo".parse().unwrap();
+            assert_eq!(s.len(), 6);
+            assert_eq!(s, "héllo");
+            assert_eq!(s.to_uppercase(), "HÉLLO");
+
+            // Pushing is all-or-nothing, even for multi-byte characters
+            s.push('!');
+            assert!(s.try_push('€').is_err());
+            assert_eq!(s.try_push_str("ab").unwrap_err().excess(), 1);
+            assert_eq!(s.as_str(), "héllo!");
+            s.push('.');
+            assert!(s.is_full());
+            assert!(write!(s, "x").is_err());
+
+            // Popping and truncating respect character boundaries
+            assert_eq!(s.pop(), Some('.'));
+            s.truncate(3);
+            assert_eq!(s.as_str(), "hé");
+            assert_eq!(s.pop(), Some('é'));
+            assert_eq!(s.as_bytes(), b"h");
+            assert!(std::panic::catch_unwind(|| StackString::<4>::try_from("é").unwrap().truncate(1)).is_err());
+
+            s.clear();
+            write!(s, "{}-{}", 4, 2).unwrap();
+            assert_eq!(format!("{s} {s:?}"), "4-2 \"4-2\"");
+            let (short, long): (char, &str) = ('é', "long");
+            assert!(write!(s, "{short}{long}").is_err());
+            assert_eq!(s, "4-2");
+            assert!(StackString::<2>::from_utf8(StackVec::from([0xC3u8, 0x28])).is_err());
+            assert_eq!(String::from(s.clone()), "4-2");
+            assert_eq!(s.into_bytes(), b"4-2".as_slice());
+            assert!("abc".parse::<StackString<2>>().is_err());
+
+            // Hashes like a `str`, so it can be looked up by one
+            let map: std::collections::HashMap<StackString<16>, u8> = [("hello".parse().unwrap(), 1)].into_iter().collect();
+            assert_eq!(map.get("hello"), Some(&1));
+            assert_eq!(map.get("world"), None);
+        }
+
+        #[cfg(feature = "arbitrary")]
+        #[test]
+        fn arbitrary() {
+            use arbitrary::{Arbitrary, Unstructured};
+
+            // Never exceeds the capacity, no matter how much data there is
+            let data: [u8; 64] = [1; 64];
+            let vec: StackVec<3, u8> = StackVec::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert_eq!(vec, [1, 1, 1]);
+            let vec: StackVec<3, u8> = StackVec::arbitrary_take_rest(Unstructured::new(&data)).unwrap();
+            assert_eq!(vec, [1, 1, 1]);
+
+            // But may also be shorter
+            let vec: StackVec<3, u8> = StackVec::arbitrary(&mut Unstructured::new(&[1, 7, 0])).unwrap();
+            assert_eq!(vec, [7]);
+            let vec: StackVec<3, u8> = StackVec::arbitrary(&mut Unstructured::new(&[])).unwrap();
+            assert!(vec.is_empty());
+        }
+
+        #[cfg(feature = "arrayvec")]
+        #[test]
+        fn arrayvec() {
+            let vec: StackVec<4, String> = StackVec::from(["A".into(), "B".into()]);
+            let array: arrayvec::ArrayVec<String, 4> = vec.clone().into();
+            assert_eq!(array.as_slice(), ["A", "B"]);
+            let array: arrayvec::ArrayVec<String, 8> = vec.into();
+            let vec: StackVec<8, String> = array.into();
+            assert_eq!(vec.as_slice(), ["A", "B"]);
+        }
+
+        #[cfg(feature = "bytemuck")]
+        #[test]
+        fn bytemuck() {
+            let mut vec: StackVec<4, u16> = StackVec::from([0x0102, 0x0304]);
+            assert_eq!(vec.as_bytes().len(), 4);
+            assert_eq!(vec.as_bytes(), [0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat());
+            vec.as_bytes_mut().fill(0xFF);
+            assert_eq!(vec, [0xFFFF, 0xFFFF]);
+
+            // Zeroed vecs are empty
+            let vec: StackVec<4, String> = bytemuck::Zeroable::zeroed();
+            assert!(vec.is_empty());
+        }
+
+        #[test]
+        fn append() {
+            // Append a smaller vec to a bigger one
+            let mut vec: StackVec<5, String> = StackVec::from(["A".into(), "B".into()]);
+            let mut vec2: StackVec<3, String> = StackVec::from(["C".into(), "D".into(), "E".into()]);
+            vec.append(&mut vec2);
+            assert_eq!(vec, ["A".into(), "B".into(), "C".into(), "D".into(), "E".into()]);
+            assert!(vec2.is_empty());
+        }
+
+        #[test]
+        fn resize() {
+            // Grow, then shrink again
+            let mut vec: StackVec<5, String> = StackVec::from(["A".into()]);
+            vec.resize(3, "B".into());
+            assert_eq!(vec, ["A".into(), "B".into(), "B".into()]);
+            vec.resize(1, "C".into());
+            assert_eq!(vec, ["A".into()]);
+
+            // Do one with a closure
+            let mut i: u32 = 0;
+            let mut vec: StackVec<5, u32> = StackVec::new();
+            vec.resize_with(5, || {
+                i += 1;
+                i
+            });
+            assert_eq!(vec, [1, 2, 3, 4, 5]);
+        }
+
+        #[test]
+        fn extend_from_slice() {
+            let mut vec: StackVec<4, String> = StackVec::from(["A".into()]);
+            vec.extend_from_slice(&["B".into(), "C".into()]);
+            assert_eq!(vec, ["A".into(), "B".into(), "C".into()]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn extend_from_slice_overflow() {
+            let mut vec: StackVec<2, u8> = StackVec::from([1]);
+            vec.extend_from_slice(&[2, 3]);
+        }
+
+        #[test]
+        fn extend_from_copy_slice() {
+            let mut vec: StackVec<1500, u8> = StackVec::from([1]);
+            vec.extend_from_copy_slice(&[2, 3]);
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(vec.try_extend_from_copy_slice(&[0; 1498]).map_err(|err| err.excess()), Err(1));
+            assert_eq!(vec.len(), 3);
+            assert_eq!(vec.try_extend_from_copy_slice(&[4; 1497]), Ok(()));
+            assert!(vec.is_full());
+            assert_eq!(vec[1499], 4);
+        }
+
+        #[test]
+        fn try_extend() {
+            let mut vec: StackVec<4, String> = StackVec::from(["A".into()]);
+            assert_eq!(vec.try_extend(["B".to_string(), "C".to_string()]).map_err(|err| err.excess()), Ok(()));
+            let err: CapacityError<_> = vec.try_extend(["D".to_string(), "E".to_string()]).unwrap_err();
+            assert_eq!(err.excess(), 1);
+            assert_eq!(err.into_element().collect::<Vec<String>>(), vec!["D".to_string(), "E".to_string()]);
+            assert_eq!(vec, ["A".into(), "B".into(), "C".into()]);
+
+            // Slices too
+            assert_eq!(vec.try_extend_from_slice(&["D".into(), "E".into(), "F".into()]).map_err(|err| err.excess()), Err(2));
+            assert_eq!(vec.try_extend_from_slice(&["D".into()]), Ok(()));
+            assert_eq!(vec, ["A".into(), "B".into(), "C".into(), "D".into()]);
+        }
+
+        #[test]
+        fn extend_until_full() {
+            let mut vec: StackVec<3, u8> = StackVec::from([1]);
+            let mut vec2: StackVec<3, u8> = StackVec::new();
+            let rest = vec.extend_until_full(2..=5);
+            vec2.extend(rest);
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(vec2, [4, 5]);
+        }
+
+        #[test]
+        fn try_from_iter() {
+            let vec: Result<StackVec<3, u8>, CapacityError<u8>> = StackVec::try_from_iter(1..=3);
+            assert_eq!(vec, Ok(StackVec::from([1, 2, 3])));
+            let vec: Result<StackVec<3, u8>, CapacityError<u8>> = StackVec::try_from_iter(1..=5);
+            assert_eq!(vec.map_err(CapacityError::into_element), Err(4));
+        }
+
+        #[test]
+        fn from_iter_size_hint() {
+            use std::cell::Cell;
+            use std::panic::{AssertUnwindSafe, catch_unwind};
+
+            let vec: StackVec<4, u8> = (0..4).collect();
+            assert_eq!(vec, [0, 1, 2, 3]);
+            let vec: StackVec<4, u8> = (0..10).filter(|i| i % 3 == 0).collect();
+            assert_eq!(vec, [0, 3, 6, 9]);
+
+            // Fails before consuming anything if the iterator is known to be too long
+            let consumed: Cell<usize> = Cell::new(0);
+            let res = catch_unwind(AssertUnwindSafe(|| (0..5).inspect(|_| consumed.set(consumed.get() + 1)).collect::<StackVec<4, u8>>()));
+            assert!(res.is_err());
+            assert_eq!(consumed.get(), 0);
+        }
+
+        #[test]
+        fn from_iter_truncated() {
+            let mut iter = 1..=5;
+            let vec: StackVec<3, u8> = StackVec::from_iter_truncated(iter.by_ref());
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(iter.next(), Some(4));
+
+            let (vec, skipped): (StackVec<3, u8>, usize) = StackVec::from_iter_truncated_counted(1..=5);
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(skipped, 2);
+        }
+
+        #[test]
+        fn from_fn() {
+            let vec: StackVec<4, String> = StackVec::from_fn(3, |i| i.to_string());
+            assert_eq!(vec, ["0", "1", "2"].map(String::from));
+            assert!(StackVec::<4, u8>::from_fn(0, |_| unreachable!()).is_empty());
+            let err: CapacityError = StackVec::<4, u8>::try_from_fn(6, |_| unreachable!()).unwrap_err();
+            assert_eq!(err.excess(), 2);
+            assert!(std::panic::catch_unwind(|| StackVec::<4, u8>::from_fn(5, |i| i as u8)).is_err());
+        }
+
+        #[test]
+        fn try_from() {
+            assert_eq!(StackVec::<3, u8>::try_from(&[1, 2][..]), Ok(StackVec::from([1, 2])));
+            assert_eq!(StackVec::<3, u8>::try_from(&[1, 2, 3, 4, 5][..]).map_err(|err| err.excess()), Err(2));
+            assert_eq!(StackVec::<3, u8>::try_from(vec![1, 2, 3]), Ok(StackVec::from([1, 2, 3])));
+            let err: CapacityError<Vec<u8>> = StackVec::<3, u8>::try_from(vec![1, 2, 3, 4]).unwrap_err();
+            assert_eq!(err.excess(), 1);
+            assert_eq!(err.into_element(), vec![1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn extend_from_within() {
+            let mut vec: StackVec<8, u8> = StackVec::from([1, 2, 3]);
+            vec.extend_from_within(1..);
+            vec.extend_from_within(..=2);
+            assert_eq!(vec, [1, 2, 3, 2, 3, 1, 2, 3]);
+
+            // Bounds at `usize::MAX` are out-of-bounds, not overflowing
+            let err = std::panic::catch_unwind(|| StackVec::<8, u8>::from([1]).extend_from_within(0..=usize::MAX)).unwrap_err();
+            assert!(err.downcast_ref::<String>().unwrap().contains("out-of-bounds"));
+            let err = std::panic::catch_unwind(|| StackVec::<8, u8>::from([1]).extend_from_within((std::ops::Bound::Excluded(usize::MAX), std::ops::Bound::Unbounded))).unwrap_err();
+            assert!(err.downcast_ref::<String>().unwrap().contains("out-of-bounds"));
+        }
+
+        #[test]
+        fn fill() {
+            let mut vec: StackVec<4, u8> = StackVec::from([1, 2]);
+            vec.fill(0);
+            assert_eq!(vec, [0, 0]);
+            vec.fill_to_capacity(7);
+            assert_eq!(vec, [7, 7, 7, 7]);
+        }
+
+        #[test]
+        fn swap() {
+            let mut vec: StackVec<4, &'static str> = StackVec::from(["A", "B", "C"]);
+            vec.swap(0, 2);
+            vec.swap(1, 1);
+            assert_eq!(vec, ["C", "B", "A"]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn swap_out_of_bounds() {
+            let mut vec: StackVec<4, u8> = StackVec::from([1, 2, 3]);
+            vec.swap(0, 3);
+        }
+
+        #[test]
+        fn first_last() {
+            // Should be usable in const functions
+            const fn first_or_zero(vec: &StackVec<3, u8>) -> u8 {
+                match vec.first() {
+                    Some(elem) => *elem,
+                    None => 0,
+                }
+            }
+            assert_eq!(first_or_zero(&StackVec::new()), 0);
+
+            let mut vec: StackVec<3, u8> = StackVec::from([1, 2, 3]);
+            assert_eq!(vec.first(), Some(&1));
+            assert_eq!(vec.last(), Some(&3));
+            *vec.first_mut().unwrap() = 4;
+            *vec.last_mut().unwrap() = 5;
+            assert_eq!(vec, [4, 2, 5]);
+        }
+
+        #[test]
+        fn pop_if() {
+            let mut vec: StackVec<3, u8> = StackVec::from([1, 2]);
+            assert_eq!(vec.pop_if(|elem| *elem == 1), None);
+            assert_eq!(vec.pop_if(|elem| *elem == 2), Some(2));
+            assert_eq!(vec, [1]);
+        }
+
+        #[test]
+        fn const_api() {
+            const VEC: StackVec<4, u8> = {
+                let mut vec: StackVec<4, u8> = StackVec::from_array([1, 2]);
+                assert!(vec.try_push(3).is_ok());
+                assert!(vec.push_within_capacity(4).is_ok());
+                assert!(vec.try_push(5).is_err());
+                assert!(matches!(vec.pop(), Some(4)));
+                vec
+            };
+            assert_eq!(VEC, [1, 2, 3]);
+
+            static EMPTY: StackVec<4, u8> = {
+                let mut vec: StackVec<4, u8> = StackVec::from_array([1, 2, 3, 4]);
+                vec.clear_copy();
+                vec
+            };
+            assert!(EMPTY.is_empty());
+
+            // Works for non-`Copy` elements too, as long as nothing is dropped
+            let vec: StackVec<3, String> = StackVec::from_array([String::from("a"), String::from("b")]);
+            assert_eq!(vec, [String::from("a"), String::from("b")]);
+        }
+
+        #[test]
+        fn insert_remove() {
+            let mut vec: StackVec<4, String> = StackVec::new();
+            vec.insert(0, "B".into());
+            vec.insert(0, "A".into());
+            vec.insert(2, "D".into());
+            vec.insert(2, "C".into());
+            assert_eq!(vec.as_slice(), ["A", "B", "C", "D"]);
+
+            assert_eq!(vec.remove(1).as_deref(), Some("B"));
+            assert_eq!(vec.remove(2).as_deref(), Some("D"));
+            assert_eq!(vec.remove(2), None);
+            assert_eq!(vec.remove(0).as_deref(), Some("A"));
+            assert_eq!(vec.as_slice(), ["C"]);
+        }
+
+        #[test]
+        fn insert_sorted() {
+            let mut vec: StackVec<5, u8> = StackVec::new();
+            assert_eq!(vec.insert_sorted(3), Ok(0));
+            assert_eq!(vec.insert_sorted(1), Ok(0));
+            assert_eq!(vec.insert_sorted(4), Ok(2));
+            assert_eq!(vec.insert_sorted(2), Ok(1));
+            assert_eq!(vec.insert_sorted(3), Ok(3));
+            assert_eq!(vec.insert_sorted(0).map_err(CapacityError::into_element), Err(0));
+            assert_eq!(vec, [1, 2, 3, 3, 4]);
+
+            // Sort by key
+            let mut vec: StackVec<3, (u8, &'static str)> = StackVec::from([(1, "A"), (3, "C")]);
+            assert_eq!(vec.insert_sorted_by_key((2, "B"), |(key, _)| *key), Ok(1));
+            assert_eq!(vec, [(1, "A"), (2, "B"), (3, "C")]);
+        }
+
+        #[test]
+        fn get() {
+            let mut vec: StackVec<5, u8> = StackVec::from([1, 2, 3]);
+            assert_eq!(vec.get(2), Some(&3));
+            assert_eq!(vec.get(3), None);
+            assert_eq!(vec.get(1..3), Some(&[2, 3][..]));
+            assert_eq!(vec.get(1..4), None);
+            assert_eq!(vec.get(..=2), Some(&[1, 2, 3][..]));
+            assert_eq!(vec.get(..=3), None);
+            assert_eq!(vec.get(3..), Some(&[][..]));
+            assert_eq!(vec.get(4..), None);
+            vec.get_mut(1..).unwrap().fill(0);
+            assert_eq!(vec, [1, 0, 0]);
+        }
+
+        #[test]
+        fn get_disjoint_mut() {
+            let mut vec: StackVec<5, u8> = StackVec::from([1, 2, 3]);
+            let [a, b] = vec.get_disjoint_mut([2, 0]).unwrap();
+            std::mem::swap(a, b);
+            assert_eq!(vec, [3, 2, 1]);
+            assert!(vec.get_disjoint_mut([0, 0]).is_none());
+            assert!(vec.get_disjoint_mut([0, 3]).is_none());
+        }
+
+        #[test]
+        fn as_ptr() {
+            let mut vec: StackVec<5, u8> = StackVec::from([1, 2, 3]);
+            let range = vec.as_ptr_range();
+            assert_eq!(range.start, vec.as_slice().as_ptr());
+            assert_eq!(unsafe { range.end.offset_from(range.start) }, 3);
+            unsafe { *vec.as_mut_ptr().add(1) = 4 };
+            assert_eq!(vec, [1, 4, 3]);
+        }
+
+        #[test]
+        fn set_len() {
+            let mut vec: StackVec<5, u8> = StackVec::new();
+            unsafe {
+                for i in 0..4 {
+                    vec.as_mut_ptr().add(i).write(i as u8);
+                }
+                vec.set_len(4);
+            }
+            assert_eq!(vec, [0, 1, 2, 3]);
+        }
+
+        #[test]
+        fn spare_capacity_mut() {
+            let mut vec: StackVec<5, String> = StackVec::from(["A".into()]);
+            let spare = vec.spare_capacity_mut();
+            assert_eq!(spare.len(), 4);
+            spare[0].write("B".into());
+            unsafe { vec.set_len(2) };
+            assert_eq!(vec, ["A".into(), "B".into()]);
+        }
+
+        #[test]
+        fn split_at_spare_mut() {
+            let mut vec: StackVec<4, u8> = StackVec::from([1, 2]);
+            let (init, spare) = vec.split_at_spare_mut();
+            spare[0].write(init[0] + init[1]);
+            init[0] = 0;
+            unsafe { vec.set_len(3) };
+            assert_eq!(vec, [0, 2, 3]);
+        }
+
+        #[test]
+        fn from_parts() {
+            let data: [MaybeUninit<String>; 3] = [MaybeUninit::new("A".into()), MaybeUninit::new("B".into()), MaybeUninit::uninit()];
+            let vec: StackVec<3, String> = unsafe { StackVec::from_parts(data, 2) };
+            assert_eq!(vec, ["A".into(), "B".into()]);
+        }
+
+        #[test]
+        fn is_full() {
+            let mut vec: StackVec<2, u8> = StackVec::new();
+            assert!(!vec.is_full());
+            assert_eq!(vec.remaining_capacity(), 2);
+            vec.push(1);
+            vec.push(2);
+            assert!(vec.is_full());
+            assert_eq!(vec.remaining_capacity(), 0);
+        }
+
+        #[test]
+        fn take() {
+            let mut vec: StackVec<3, Option<u8>> = StackVec::from([Some(1), Some(2)]);
+            assert_eq!(vec.take(0), Some(Some(1)));
+            assert_eq!(vec.take(2), None);
+            assert_eq!(vec, [None, Some(2)]);
+        }
+
+        #[test]
+        fn replace() {
+            let mut vec: StackVec<3, String> = StackVec::from(["A".into(), "B".into()]);
+            assert_eq!(vec.replace(1, "C".into()), Some("B".into()));
+            assert_eq!(vec.replace(2, "D".into()), None);
+            assert_eq!(vec, ["A".into(), "C".into()]);
+        }
+
+        #[test]
+        fn dedup() {
+            let mut vec: StackVec<8, String> = StackVec::from(["A".into(), "A".into(), "B".into(), "A".into(), "C".into(), "C".into(), "C".into()]);
+            vec.dedup();
+            assert_eq!(vec, ["A".into(), "B".into(), "A".into(), "C".into()]);
+        }
+
+        #[test]
+        fn dedup_by() {
+            let mut vec: StackVec<5, &'static str> = StackVec::from(["a", "A", "b", "B", "a"]);
+            vec.dedup_by(|lhs, rhs| lhs.eq_ignore_ascii_case(rhs));
+            assert_eq!(vec, ["a", "b", "a"]);
+
+            let mut vec: StackVec<5, u8> = StackVec::from([10, 11, 20, 21, 22]);
+            vec.dedup_by_key(|elem| *elem / 10);
+            assert_eq!(vec, [10, 20]);
+
+            // A panicking closure keeps the elements visited so far deduplicated, and the others untouched
+            let mut vec: StackVec<6, String> = StackVec::from(["a", "a", "b", "b", "c", "c"].map(String::from));
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vec.dedup_by(|lhs, rhs| if lhs == "c" { panic!("Oh no") } else { lhs == rhs })
+            }));
+            assert!(res.is_err());
+            assert_eq!(vec, ["a", "b", "c", "c"].map(String::from));
+        }
+
+        #[test]
+        fn contains() {
+            let vec: StackVec<5, String> = StackVec::from(["A".into(), "B".into()]);
+            assert!(vec.contains(&"B".into()));
+            assert!(!vec.contains(&"C".into()));
+        }
+
+        #[test]
+        fn remove_item() {
+            let mut vec: StackVec<5, u8> = StackVec::from([1, 2, 3, 2]);
+            assert_eq!(vec.remove_item(&2), Some(2));
+            assert_eq!(vec.remove_item(&4), None);
+            assert_eq!(vec, [1, 3, 2]);
+        }
+
+        #[test]
+        fn insert_many() {
+            let mut vec: StackVec<8, String> = StackVec::from(["A".into(), "E".into()]);
+            vec.insert_many(1, ["B".into(), "C".into()]);
+            vec.insert_slice(3, &["D".into()]);
+            vec.insert_slice(5, &["F".into()]);
+            assert_eq!(vec, ["A".into(), "B".into(), "C".into(), "D".into(), "E".into(), "F".into()]);
+
+            // A panicking iterator keeps the elements inserted so far, followed by the tail
+            let mut vec: StackVec<8, String> = StackVec::from(["A".into(), "E".into()]);
+            let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vec.insert_many(1, (0..3).map(|i| if i < 2 { i.to_string() } else { panic!("Oh no") }))
+            }));
+            assert!(res.is_err());
+            assert_eq!(vec, ["A", "0", "1", "E"].map(String::from));
+        }
+
+        #[test]
+        fn push_rotate() {
+            let mut vec: StackVec<3, u8> = StackVec::new();
+            for i in 1..=3 {
+                assert_eq!(vec.push_rotate(i), None);
+            }
+            assert_eq!(vec.push_rotate(4), Some(1));
+            assert_eq!(vec.push_rotate(5), Some(2));
+            assert_eq!(vec, [3, 4, 5]);
+            assert_eq!(StackVec::<0, u8>::new().push_rotate(1), Some(1));
+        }
+
+        #[test]
+        fn as_ref() {
+            fn sum(elems: impl AsRef<[u8]>) -> u8 { elems.as_ref().iter().sum() }
+            fn zero(mut elems: impl AsMut<[u8]>) { elems.as_mut().fill(0) }
+
+            let mut vec: StackVec<5, u8> = StackVec::from([1, 2, 3]);
+            assert_eq!(sum(&vec), 6);
+            zero(&mut vec);
+            assert_eq!(vec, [0, 0, 0]);
+        }
+
+        #[test]
+        fn eq() {
+            let vec: StackVec<5, u8> = StackVec::from([1, 2, 3]);
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(vec, &[1, 2, 3][..]);
+            assert_eq!(vec, [1, 2, 3][..]);
+            assert_eq!(vec, vec![1, 2, 3]);
+            assert_ne!(vec, [1, 2]);
+            assert_eq!([1, 2, 3], vec);
+            assert_eq!(&[1, 2, 3][..], vec);
+            assert_eq!([1, 2, 3][..], vec);
+            assert_eq!(vec![1, 2, 3], vec);
+            assert_ne!(vec![1, 2, 4], vec);
+
+            // Differing in only some elements is still unequal
+            assert_ne!(vec, [1, 2, 4]);
+            assert_ne!(vec, StackVec::<4, u8>::from([1, 2, 4]));
+            assert!(vec != vec![1, 5, 3]);
+        }
+
+        #[test]
+        fn hash() {
+            use std::collections::HashSet;
+
+            // Must hash like slices, as StackVecs can be borrowed as them
+            let mut set: HashSet<StackVec<5, u8>> = HashSet::new();
+            set.insert(StackVec::from([1, 2, 3]));
+            assert!(set.contains(&[1, 2, 3][..]));
+            assert!(!set.contains(&[1, 2][..]));
+            assert_eq!(format!("{:?}", StackVec::<5, u8>::from([1, 2, 3])), "[1, 2, 3]");
+        }
+
+        #[test]
+        fn cross_capacity_cmp() {
+            let small: StackVec<3, u8> = StackVec::from([1, 2, 3]);
+            let large: StackVec<8, u8> = StackVec::from([1, 2, 3]);
+            assert_eq!(small, large);
+            assert_eq!(large, small);
+            assert_eq!(small.partial_cmp(&large), Some(std::cmp::Ordering::Equal));
+
+            let shorter: StackVec<8, u8> = StackVec::from([1, 2]);
+            assert_ne!(small, shorter);
+            assert!(shorter < small);
+            assert!(small > shorter);
+
+            let other: StackVec<5, u8, DropOnFull> = StackVec::from([1, 3]);
+            assert!(small < other);
+            assert!(other > large);
+        }
+
+        #[test]
+        fn ord() {
+            use std::cmp::Ordering;
+
+            // Matches the ordering of `Vec`s
+            let vecs: [Vec<u8>; 5] = [vec![], vec![1], vec![1, 2], vec![1, 3], vec![2]];
+            for lhs in &vecs {
+                for rhs in &vecs {
+                    let lhs_stack: StackVec<4, u8> = StackVec::from_iter(lhs.iter().copied());
+                    let rhs_stack: StackVec<4, u8> = StackVec::from_iter(rhs.iter().copied());
+                    assert_eq!(lhs_stack.cmp(&rhs_stack), lhs.cmp(rhs));
+                }
+            }
+
+            // Incomparable elements make the StackVecs incomparable
+            let nan: StackVec<4, f32> = StackVec::from([1.0, f32::NAN]);
+            assert_eq!(nan.partial_cmp(&nan), None);
+            assert_eq!(nan.partial_cmp(&StackVec::<4, f32>::from([2.0])), Some(Ordering::Less));
+        }
+
+        #[test]
+        fn into_heap() {
+            let vec: StackVec<5, String> = StackVec::from(["A".into(), "B".into()]);
+            let heap: Vec<String> = vec.clone().into_vec();
+            assert_eq!(heap, ["A", "B"]);
+            assert_eq!(heap.capacity(), 2);
+            let boxed: Box<[String]> = vec.clone().into();
+            assert_eq!(&*boxed, ["A", "B"]);
+            let deque: VecDeque<String> = vec.into();
+            assert_eq!(deque, ["A", "B"]);
+        }
+
+        #[test]
+        fn into_iter() {
+            let vec: StackVec<8, String> = StackVec::from_iter(["A", "B", "C", "D", "E", "F"].map(String::from));
+
+            // Both ends can be consumed, and the rest is visible as a slice
+            let mut iter = vec.clone().into_iter();
+            assert_eq!(iter.next().as_deref(), Some("A"));
+            assert_eq!(iter.next_back().as_deref(), Some("F"));
+            assert_eq!(iter.as_slice(), ["B", "C", "D", "E"]);
+            iter.as_mut_slice()[0].push('!');
+            let clone = iter.clone();
+            assert_eq!(format!("{clone:?}"), r#"IntoIter(["B!", "C", "D", "E"])"#);
+            assert_eq!(iter.nth(1).as_deref(), Some("C"));
+            assert_eq!(iter.len(), 2);
+            assert_eq!(iter.last().as_deref(), Some("E"));
+            assert_eq!(clone.rev().collect::<Vec<String>>(), ["E", "D", "C", "B!"]);
+
+            // Skipping beyond the end leaves nothing
+            let mut iter = vec.clone().into_iter();
+            assert_eq!(iter.nth(6), None);
+            assert_eq!(iter.next_back(), None);
+            assert_eq!(vec.into_iter().fold(String::new(), |acc, elem| acc + &elem), "ABCDEF");
+        }
+
+        #[test]
+        fn into_larger_smaller() {
+            let vec: StackVec<3, String> = StackVec::from(["a", "b"].map(String::from));
+            let vec: StackVec<5, String> = vec.into_larger();
+            assert_eq!(vec, ["a", "b"].map(String::from));
+            assert_eq!(vec.capacity(), 5);
+
+            let vec: StackVec<2, String> = vec.try_into_smaller().unwrap();
+            assert_eq!(vec, ["a", "b"].map(String::from));
+            let err: CapacityError<StackVec<2, String>> = vec.try_into_smaller::<1>().unwrap_err();
+            assert_eq!(err.excess(), 1);
+            assert_eq!(err.into_element(), ["a", "b"].map(String::from));
+        }
+
+        #[test]
+        fn map() {
+            let vec: StackVec<4, u8> = StackVec::from([1, 2, 3]);
+            let vec: StackVec<4, String> = vec.map(|elem| elem.to_string());
+            assert_eq!(vec, ["1", "2", "3"].map(String::from));
+            let vec: StackVec<4, usize, DropOnFull, u8> = StackVec::<4, String, DropOnFull, u8>::from(["a", "bc"].map(String::from)).map(|elem| elem.len());
+            assert_eq!(vec, [1, 2]);
+            assert!(StackVec::<4, u8>::new().map(u16::from).is_empty());
+        }
+
+        #[test]
+        fn zip_unzip() {
+            let names: StackVec<4, String> = StackVec::from(["a", "b", "c"].map(String::from));
+            let ages: StackVec<4, u8, DropOnFull> = StackVec::from([1, 2]);
+            let pairs: StackVec<4, (String, u8)> = names.zip(ages);
+            assert_eq!(pairs, [(String::from("a"), 1), (String::from("b"), 2)]);
+
+            let (names, ages): (StackVec<4, String>, StackVec<4, u8>) = pairs.unzip();
+            assert_eq!(names, ["a", "b"].map(String::from));
+            assert_eq!(ages, [1, 2]);
+            let (lhs, rhs): (StackVec<2, u8>, StackVec<2, ()>) = StackVec::new().unzip();
+            assert!(lhs.is_empty() && rhs.is_empty());
+        }
+
+        #[test]
+        fn partition() {
+            let vec: StackVec<6, String> = StackVec::from(["a", "bb", "c", "dd", "e"].map(String::from));
+            let (short, long): (StackVec<6, String>, StackVec<6, String>) = vec.partition(|elem| elem.len() == 1);
+            assert_eq!(short, ["a", "c", "e"].map(String::from));
+            assert_eq!(long, ["bb", "dd"].map(String::from));
+            let (all, none): (StackVec<3, u8>, StackVec<3, u8>) = StackVec::from([1, 2, 3]).partition(|_| true);
+            assert_eq!(all, [1, 2, 3]);
+            assert!(none.is_empty());
+        }
+
+        #[test]
+        fn each_ref_mut() {
+            let mut vec: StackVec<4, String> = StackVec::from(["c", "a", "b"].map(String::from));
+            {
+                let mut view: StackVec<4, &String> = vec.each_ref();
+                view.sort();
+                assert_eq!(view, [&String::from("a"), &String::from("b"), &String::from("c")]);
+            }
+            assert_eq!(vec, ["c", "a", "b"].map(String::from));
+
+            for elem in vec.each_mut() {
+                elem.push('!');
+            }
+            assert_eq!(vec, ["c!", "a!", "b!"].map(String::from));
+        }
+
+        #[test]
+        fn into_chunks() {
+            let vec: StackVec<8, String> = ["a", "b", "c", "d", "e"].into_iter().map(String::from).collect();
+            let mut chunks = vec.into_chunks::<2>();
+            assert_eq!(chunks.len(), 3);
+            assert_eq!(chunks.next().unwrap(), ["a", "b"].map(String::from));
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks.next().unwrap(), ["c", "d"].map(String::from));
+            assert_eq!(chunks.next().unwrap(), ["e"].map(String::from));
+            assert_eq!(chunks.next(), None);
+            assert_eq!(StackVec::<4, u8>::new().into_chunks::<3>().count(), 0);
+            let chunks: Vec<StackVec<3, u8>> = StackVec::<4, u8>::from([1, 2, 3]).into_chunks().collect();
+            assert_eq!(chunks, [StackVec::<3, u8>::from([1, 2, 3])]);
+        }
+
+        #[test]
+        fn into_inner() {
+            let vec: StackVec<3, String> = StackVec::from(["A".into(), "B".into()]);
+            let mut vec: StackVec<3, String> = vec.into_inner().unwrap_err();
+            assert_eq!(vec.len(), 2);
+            vec.push("C".into());
+            let arr: [String; 3] = vec.try_into().unwrap();
+            assert_eq!(arr, ["A", "B", "C"]);
+        }
+
+        #[test]
+        fn from_smaller_array() {
+            let vec: StackVec<5, String> = StackVec::from(["A".into(), "B".into()]);
+            assert_eq!(vec.len(), 2);
+            assert_eq!(vec.capacity(), 5);
+            let vec: StackVec<2, u8> = StackVec::from([1, 2]);
+            assert!(vec.is_full());
+            let vec: StackVec<0, u8> = StackVec::from([]);
+            assert!(vec.is_empty());
+        }
+
+        #[cfg(feature = "generic-const-exprs")]
+        #[test]
+        fn generic_const_exprs() {
+            let a: StackVec<3, String> = StackVec::from([String::from("a"), String::from("b")]);
+            let b: StackVec<2, String> = StackVec::from([String::from("c")]);
+            let vec: StackVec<5, String> = a.concat(b);
+            assert_eq!(vec, ["a", "b", "c"].map(String::from));
+
+            let (head, tail): (StackVec<2, String>, StackVec<3, String>) = vec.split::<2>();
+            assert_eq!(head, ["a", "b"].map(String::from));
+            assert_eq!(tail, ["c"].map(String::from));
+            let (head, tail): (StackVec<4, u8>, StackVec<0, u8>) = StackVec::<4, u8>::from([1, 2]).split::<4>();
+            assert_eq!(head, [1, 2]);
+            assert!(tail.is_empty());
+        }
+
+        #[cfg(feature = "heapless")]
+        #[test]
+        fn heapless() {
+            let vec: StackVec<4, String> = StackVec::from(["A".into(), "B".into()]);
+            let heap: heapless::Vec<String, 4> = vec.clone().into();
+            assert_eq!(heap, ["A", "B"]);
+            let heap: heapless::Vec<String, 8> = vec.into();
+            let vec: StackVec<8, String> = heap.into();
+            assert_eq!(vec.as_slice(), ["A", "B"]);
+            let vec: StackVec<8, String> = heapless::Vec::<String, 2>::new().into();
+            assert!(vec.is_empty());
+        }
+
+        #[cfg(feature = "proptest")]
+        #[test]
+        fn proptest() {
+            use proptest::prelude::*;
+            use proptest::strategy::ValueTree;
+            use proptest::test_runner::TestRunner;
+
+            // Lengths are clamped to the capacity
+            let mut runner: TestRunner = TestRunner::deterministic();
+            let strategy = crate::proptest::stack_vec::<3, _, DropOnFull>(any::<u8>(), 2..10);
+            for _ in 0..32 {
+                let vec: StackVec<3, u8, DropOnFull> = strategy.new_tree(&mut runner).unwrap().current();
+                assert!(vec.len() >= 2 && vec.len() <= 3);
+            }
+
+            // Shrinks the length first, then the elements
+            let mut tree = crate::proptest::stack_vec::<4, _, DropOnFull>(1..100u8, 1..=4).new_tree(&mut runner).unwrap();
+            while tree.current().len() > 1 {
+                assert!(tree.simplify());
+            }
+            while tree.simplify() {}
+            assert_eq!(tree.current(), [1]);
+        }
+
+        #[cfg(feature = "quickcheck")]
+        #[test]
+        fn quickcheck() {
+            use quickcheck::{Arbitrary, Gen};
+
+            // Never exceeds the capacity
+            let mut g: Gen = Gen::new(100);
+            for _ in 0..32 {
+                assert!(StackVec::<3, u8>::arbitrary(&mut g).len() <= 3);
+            }
+
+            // Shrinks to shorter vecs first
+            let vec: StackVec<3, u8> = StackVec::from([3, 4, 5]);
+            let first: StackVec<3, u8> = vec.shrink().next().unwrap();
+            assert!(first.is_empty());
+            assert!(vec.shrink().all(|shrunk| shrunk.len() <= 3 && shrunk.as_slice() != vec.as_slice()));
+        }
+
+        #[cfg(feature = "rand")]
+        #[test]
+        fn rand() {
+            use rand::SeedableRng as _;
+            use rand::rngs::StdRng;
+
+            let mut rng: StdRng = StdRng::seed_from_u64(42);
+            let vec: StackVec<8, u32> = StackVec::random(&mut rng, 5);
+            assert_eq!(vec.len(), 5);
+            assert!(StackVec::<4, u32>::try_random(&mut rng, 5).is_err());
+            let full: StackVec<8, u8> = rand::Rng::random(&mut rng);
+            assert!(full.is_full());
+
+            // Shuffling keeps the elements, and choosing picks one of them
+            let mut vec: StackVec<8, u32> = StackVec::from([1, 2, 3, 4, 5]);
+            vec.shuffle(&mut rng);
+            let mut sorted: Vec<u32> = vec.to_vec();
+            sorted.sort();
+            assert_eq!(sorted, [1, 2, 3, 4, 5]);
+            assert!(vec.contains(vec.choose(&mut rng).unwrap()));
+            *vec.choose_mut(&mut rng).unwrap() = 0;
+            assert!(vec.contains(&0));
+            assert_eq!(StackVec::<8, u32>::new().choose(&mut rng), None);
+        }
+
+        #[cfg(feature = "rkyv")]
+        #[test]
+        fn rkyv() {
+            use rkyv::rancor::Error;
+            use rkyv::string::ArchivedString;
+            use rkyv::util::AlignedVec;
+
+            use crate::ArchivedStackVec;
+
+            let vec: StackVec<4, String> = StackVec::from(["A".into(), "B".into()]);
+            let bytes: AlignedVec = rkyv::to_bytes::<Error>(&vec).unwrap();
+            let archived: &ArchivedStackVec<4, ArchivedString> = rkyv::access::<_, Error>(&bytes).unwrap();
+            assert_eq!(archived.len(), 2);
+            assert_eq!(archived.capacity(), 4);
+            assert_eq!(archived[1], "B");
+            assert_eq!(rkyv::deserialize::<StackVec<4, String>, Error>(archived).unwrap(), vec);
+
+            // Corrupt the length of an inline vec
+            let vec: StackVec<4, u8> = StackVec::from([1, 2]);
+            let mut bytes: AlignedVec = rkyv::to_bytes::<Error>(&vec).unwrap();
+            assert!(rkyv::access::<ArchivedStackVec<4, u8>, Error>(&bytes).is_ok());
+            bytes[0] = 5;
+            assert!(rkyv::access::<ArchivedStackVec<4, u8>, Error>(&bytes).is_err());
+        }
+
+        #[cfg(feature = "schemars")]
+        #[test]
+        fn schemars() {
+            use schemars::{Schema, schema_for};
+
+            let schema: Schema = schema_for!(StackVec<4, u8>);
+            assert_eq!(schema.get("type").unwrap(), "array");
+            assert_eq!(schema.get("maxItems").unwrap(), 4);
+            assert_eq!(schema.get("items").unwrap().get("type").unwrap(), "integer");
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde() {
+            let vec: StackVec<3, u8> = StackVec::from([1, 2]);
+            let json: String = serde_json::to_string(&vec).unwrap();
+            assert_eq!(json, "[1,2]");
+            assert_eq!(serde_json::from_str::<StackVec<3, u8>>(&json).unwrap(), vec);
+            assert_eq!(serde_json::from_str::<StackVec<3, u8>>("[1,2,3]").unwrap(), [1, 2, 3]);
+
+            // Too many elements
+            let err: serde_json::Error = serde_json::from_str::<StackVec<3, u8>>("[1,2,3,4,5]").unwrap_err();
+            assert!(err.to_string().starts_with("invalid length 5, expected a sequence of at most 3 elements"), "{err}");
+        }
+
+        #[cfg(feature = "serde_bytes")]
+        #[test]
+        fn serde_bytes() {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+            use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
+
+            #[derive(Debug, PartialEq)]
+            struct Bytes(StackVec<3, u8>);
+            impl Serialize for Bytes {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serde_bytes::serialize(&self.0, serializer) }
+            }
+            impl<'de> Deserialize<'de> for Bytes {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { serde_bytes::deserialize(deserializer).map(Bytes) }
+            }
+
+            assert_tokens(&Bytes(StackVec::from([1, 2])), &[Token::Bytes(&[1, 2])]);
+            assert_de_tokens(&Bytes(StackVec::from([1, 2])), &[Token::Seq { len: Some(2) }, Token::U8(1), Token::U8(2), Token::SeqEnd]);
+            assert_de_tokens_error::<Bytes>(&[Token::Bytes(&[1, 2, 3, 4])], "invalid length 4, expected a byte string of at most 3 bytes");
+        }
+
+        #[cfg(feature = "repr-c")]
+        #[test]
+        fn repr_c() {
+            use std::mem::{align_of, offset_of, size_of};
+
+            assert_eq!(offset_of!(StackVec<3, u8>, data), 0);
+            assert_eq!(offset_of!(StackVec<3, u8>, len), align_of::<usize>());
+            assert_eq!(offset_of!(StackVec<3, u64, DropOnFull>, len), 3 * size_of::<u64>());
+            assert_eq!(size_of::<StackVec<3, u8>>(), 2 * size_of::<usize>());
+        }
+
+        #[test]
+        fn len_type() {
+            use std::mem::size_of;
+
+            // Smaller length types make for smaller StackVecs
+            assert_eq!(size_of::<StackVec<16, u8, PanicOnFull, u8>>(), 17);
+            assert_eq!(size_of::<StackVec<16, u8, PanicOnFull, u16>>(), 18);
+            assert_eq!(size_of::<StackVec<255, u8, PanicOnFull, u8>>(), 256);
+
+            // But behave the same
+            let mut vec: StackVec<4, String, PanicOnFull, u8> = StackVec::new();
+            vec.push("B".into());
+            vec.insert(0, "A".into());
+            vec.extend(["C".to_string(), "D".to_string()]);
+            assert!(vec.is_full());
+            assert_eq!(vec.remove(1).as_deref(), Some("B"));
+            assert_eq!(vec.len(), 3);
+            assert_eq!(vec, StackVec::<4, String>::from(["A".into(), "C".into(), "D".into()]));
+            assert_eq!(vec.into_iter().rev().collect::<Vec<String>>(), ["D", "C", "A"]);
+            let vec: StackVec<8, u8, DropOnFull, u16> = (0..10).collect();
+            assert_eq!(vec.len(), 8);
+            assert_eq!(vec.with_policy::<ErrorOnFull>().try_push(8).map_err(|err| err.into_element()), Err(8));
+        }
+
+        #[test]
+        fn policies() {
+            // Drop
+            let mut vec: StackVec<2, u8, DropOnFull> = StackVec::new();
+            assert_eq!(vec.push(1), None);
+            vec.extend([2, 3, 4]);
+            assert_eq!(vec.push(5), Some(5));
+            assert_eq!(vec, [1, 2]);
+
+            // Overwrite
+            let mut vec: StackVec<2, u8, OverwriteOldest> = StackVec::from_iter([1, 2, 3]);
+            assert_eq!(vec.push(4), Some(2));
+            assert_eq!(vec, [3, 4]);
+
+            // Error
+            let mut vec: StackVec<2, u8, ErrorOnFull> = StackVec::new();
+            assert_eq!(vec.push(1), Ok(()));
+            assert_eq!(vec.extend([2, 3, 4]).map_err(CapacityError::into_element), Err(3));
+            assert_eq!(vec, [1, 2]);
+
+            // Converting between them is free
+            let vec: StackVec<2, u8> = vec.with_policy();
+            assert_eq!(vec, [1, 2]);
+        }
+
+        #[test]
+        fn push_pop_front() {
+            let mut vec: StackVec<3, u8> = StackVec::new();
+            vec.push_front(1);
+            vec.push_front(2);
+            vec.push(3);
+            assert_eq!(vec, [2, 1, 3]);
+            assert_eq!(vec.pop_front(), Some(2));
+            assert_eq!(vec.pop_front(), Some(1));
+            assert_eq!(vec.pop_front(), Some(3));
+            assert_eq!(vec.pop_front(), None);
+        }
+
+        #[test]
+        fn drop_elements() {
+            use std::rc::Rc;
+
+            let rc: Rc<()> = Rc::new(());
+            let mut vec: StackVec<8, Rc<()>> = StackVec::from_iter((0..6).map(|_| rc.clone()));
+            vec.truncate(4);
+            assert_eq!(Rc::strong_count(&rc), 5);
+            let mut iter = vec.clone().into_iter();
+            iter.next();
+            drop(iter);
+            assert_eq!(Rc::strong_count(&rc), 5);
+            vec.clear();
+            assert_eq!(Rc::strong_count(&rc), 1);
+            vec.push(rc.clone());
+            drop(vec);
+            assert_eq!(Rc::strong_count(&rc), 1);
+        }
+
+        #[test]
+        fn copy() {
+            let vec: StackVec<8, u8> = StackVec::from([1, 2, 3]);
+            let copy: StackVec<8, u8> = vec.copy();
+            assert_eq!(copy.as_slice(), [1, 2, 3]);
+            assert_eq!(copy.capacity(), 8);
+            assert!(StackVec::<8, u8>::new().copy().is_empty());
+        }
+
+        #[test]
+        fn copy_within() {
+            let mut vec: StackVec<8, u8> = StackVec::from([1, 2, 3, 4, 5]);
+            vec.copy_within(2.., 0);
+            vec.truncate(3);
+            assert_eq!(vec, [3, 4, 5]);
+        }
+
+        #[test]
+        fn remove_indices() {
+            let mut vec: StackVec<6, String> = StackVec::from(["A".into(), "B".into(), "C".into(), "D".into(), "E".into()]);
+            vec.remove_indices(&[0, 2, 3]);
+            assert_eq!(vec, ["B".into(), "E".into()]);
+            vec.remove_indices(&[]);
+            assert_eq!(vec, ["B".into(), "E".into()]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn remove_indices_unsorted() {
+            let mut vec: StackVec<6, u8> = StackVec::from([1, 2, 3]);
+            vec.remove_indices(&[1, 0]);
+        }
+
+        #[cfg(feature = "smallvec")]
+        #[test]
+        fn smallvec() {
+            use smallvec::SmallVec;
+
+            let vec: StackVec<4, String> = StackVec::from(["A".into(), "B".into()]);
+            let small: SmallVec<[String; 4]> = vec.clone().into();
+            assert!(!small.spilled());
+            assert_eq!(small.as_slice(), ["A", "B"]);
+            let vec2: StackVec<2, String> = small.try_into().unwrap();
+            assert_eq!(vec2.as_slice(), ["A", "B"]);
+
+            // SmallVecs may spill
+            let small: SmallVec<[String; 1]> = vec.into();
+            assert!(small.spilled());
+            let err: CapacityError<SmallVec<[String; 1]>> = StackVec::<1, String>::try_from(small.clone()).unwrap_err();
+            assert_eq!(err.excess(), 1);
+            let vec: StackVec<3, String> = small.try_into().unwrap();
+            assert_eq!(vec.as_slice(), ["A", "B"]);
+        }
+
+        #[cfg(feature = "tinyvec")]
+        #[test]
+        fn tinyvec() {
+            use tinyvec::{ArrayVec, TinyVec};
+
+            let vec: StackVec<4, String> = StackVec::from(["A".into(), "B".into()]);
+            let array: ArrayVec<[String; 4]> = vec.clone().into();
+            assert_eq!(array.as_slice(), ["A", "B"]);
+            let vec2: StackVec<8, String> = array.into();
+            assert_eq!(vec2.as_slice(), ["A", "B"]);
+
+            // TinyVecs may spill
+            let tiny: TinyVec<[String; 1]> = vec.into();
+            assert!(tiny.is_heap());
+            let vec: StackVec<2, String> = tiny.clone().try_into().unwrap();
+            assert_eq!(vec.as_slice(), ["A", "B"]);
+            let err: CapacityError<TinyVec<[String; 1]>> = StackVec::<1, String>::try_from(tiny).unwrap_err();
+            assert_eq!(err.excess(), 1);
+        }
+
+        #[cfg(feature = "ufmt")]
+        #[test]
+        fn ufmt() {
+            use std::convert::Infallible;
+
+            use ufmt::{Formatter, uDebug, uDisplay, uWrite};
+
+            /// Collects the output in a [`String`], as `ufmt` only implements [`uWrite`] for it with its `std` feature.
+            struct Buffer(String);
+            impl uWrite for Buffer {
+                type Error = Infallible;
+
+                fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                    self.0.push_str(s);
+                    Ok(())
+                }
+            }
+
+            let vec: StackVec<4, u8> = StackVec::from([1, 2, 3]);
+            let mut buf: Buffer = Buffer(String::new());
+            uDebug::fmt(&vec, &mut Formatter::new(&mut buf)).unwrap();
+            assert_eq!(buf.0, "[1, 2, 3]");
+            buf.0.clear();
+            uDisplay::fmt(&vec, &mut Formatter::new(&mut buf)).unwrap();
+            assert_eq!(buf.0, "[1, 2, 3]");
+        }
+
+        #[cfg(feature = "zerocopy")]
+        #[test]
+        fn zerocopy() {
+            // Parse from an unaligned buffer
+            let buf: [u8; 7] = [0, 1, 0, 2, 0, 3, 9];
+            let (vec, rest): (StackVec<4, zerocopy::big_endian::U16>, &[u8]) = StackVec::read_from_prefix(&buf[1..], 2).unwrap();
+            assert_eq!(vec.iter().map(|elem| elem.get()).collect::<Vec<u16>>(), [0x0100, 0x0200]);
+            assert_eq!(rest, [3, 9]);
+            assert!(StackVec::<4, u16>::read_from_prefix(&buf, 4).is_none());
+            assert!(StackVec::<2, u16>::read_from_bytes(&buf[..6]).is_none());
+            assert!(StackVec::<4, u16>::read_from_bytes(&buf).is_none());
+            assert_eq!(StackVec::<4, u16>::read_from_bytes(&buf[..6]).unwrap().len(), 3);
+
+            // Emit again
+            let vec: StackVec<4, u8> = StackVec::from([1, 2, 3]);
+            let mut buf: [u8; 4] = [0; 4];
+            assert_eq!(vec.write_to_prefix(&mut buf).unwrap(), [0]);
+            assert_eq!(buf, [1, 2, 3, 0]);
+            assert!(vec.write_to_prefix(&mut buf[..2]).is_none());
+        }
+    }
+
+    /// Covers the fallible API, which is available with and without the `no-panic` feature.
+    mod checked {
+        use crate::{CapacityError, ContiguousVec, HybridVec, InsertError, StackDeque, StackMap, StackSet, StackString, StackVec};
+
+        #[test]
+        fn push_within_capacity() {
+            let mut vec: StackVec<2, String> = StackVec::new();
+            assert_eq!(vec.push_within_capacity("A".into()), Ok(()));
+            assert_eq!(vec.push_within_capacity("B".into()), Ok(()));
+            assert_eq!(vec.push_within_capacity("C".into()), Err("C".into()));
+            assert_eq!(vec, ["A".into(), "B".into()]);
+        }
+
+        #[test]
+        fn try_push() {
+            let mut vec: StackVec<1, String> = StackVec::new();
+            assert_eq!(vec.try_push("A".into()), Ok(()));
+            let err: CapacityError<String> = vec.try_push("B".into()).unwrap_err();
+            assert_eq!(err.to_string(), "Insufficient capacity in StackVec of capacity 1 (1 element(s) did not fit)");
+            assert_eq!(err.capacity(), 1);
+            let boxed: Box<dyn std::error::Error> = Box::new(err.clone().simplify());
+            assert_eq!(boxed.to_string(), "Insufficient capacity in StackVec of capacity 1 (1 element(s) did not fit)");
+            assert_eq!(err.into_element(), "B");
+            assert_eq!(vec, ["A".into()]);
+        }
+
+        #[test]
+        fn try_insert() {
+            let mut vec: StackVec<3, String> = StackVec::from(["A".into(), "C".into()]);
+            assert_eq!(vec.try_insert(1, "B".into()), Ok(()));
+            assert!(matches!(vec.try_insert(0, "D".into()), Err(InsertError::Capacity(_))));
+            vec.pop();
+            assert!(matches!(vec.try_insert(3, "D".into()), Err(InsertError::OutOfBounds { idx: 3, len: 2, .. })));
+            assert_eq!(vec, ["A".into(), "B".into()]);
+        }
+
+        #[test]
+        fn get_remove_pop() {
+            let mut vec: StackVec<4, u8> = StackVec::try_from_iter([1, 2, 3]).unwrap();
+            assert_eq!(vec.get(2), Some(&3));
+            assert_eq!(vec.get(3), None);
+            assert_eq!(vec.get(1..4), None);
+            *vec.get_mut(0).unwrap() = 4;
+            assert_eq!(vec.get_mut(5), None);
+            assert_eq!(vec.remove(3), None);
+            assert_eq!(vec.swap_remove(0), Some(4));
+            assert_eq!(vec.pop(), Some(2));
+            assert_eq!(vec.pop(), Some(3));
+            assert_eq!(vec.pop(), None);
+        }
+
+        #[test]
+        fn try_extend_from_slice() {
+            let mut vec: StackVec<3, u8> = StackVec::new();
+            assert!(vec.try_extend_from_slice(&[1, 2]).is_ok());
+            let err: CapacityError = vec.try_extend_from_slice(&[3, 4]).unwrap_err();
+            assert_eq!((err.capacity(), err.excess()), (3, 1));
+            assert_eq!(vec.as_slice(), [1, 2]);
+        }
+
+        #[test]
+        fn try_construct() {
+            assert!(StackVec::<2, u8>::try_from_iter([1, 2, 3]).is_err());
+            assert_eq!(StackVec::<3, usize>::try_from_fn(3, |i| i * 2).unwrap().as_slice(), [0, 2, 4]);
+            assert!(StackVec::<3, usize>::try_from_fn(4, |i| i).is_err());
+            assert!(StackVec::<2, u8>::try_from(&[1, 2, 3][..]).is_err());
+            assert_eq!(StackVec::<3, u8>::try_from(vec![1, 2]).unwrap().as_slice(), [1, 2]);
+            assert_eq!(StackVec::<1, u8>::try_from(vec![1, 2]).unwrap_err().into_element(), [1, 2]);
+        }
+
+        #[test]
+        fn try_convert() {
+            let vec: StackVec<4, u8> = StackVec::try_from_iter([1, 2]).unwrap();
+            let small: StackVec<2, u8> = vec.try_into_smaller().unwrap();
+            assert_eq!(small.as_slice(), [1, 2]);
+            let vec: StackVec<4, u8> = StackVec::try_from_iter([1, 2, 3]).unwrap();
+            assert_eq!(vec.try_into_smaller::<2>().unwrap_err().into_element().as_slice(), [1, 2, 3]);
+            let full: StackVec<2, u8> = StackVec::try_from_iter([1, 2]).unwrap();
+            assert_eq!(<[u8; 2]>::try_from(full), Ok([1, 2]));
+        }
+
+        #[test]
+        fn contiguous_vec() {
+            fn push_up_to<V: ContiguousVec<u8>>(vec: &mut V, n: u8) -> u8 {
+                for i in 0..n {
+                    if vec.try_push(i).is_err() {
+                        return i;
+                    }
+                }
+                n
+            }
+
+            let mut stack: StackVec<3, u8> = StackVec::new();
+            assert_eq!(push_up_to(&mut stack, 5), 3);
+            assert!(ContiguousVec::try_insert(&mut stack, 0, 42).is_err());
+            assert_eq!(ContiguousVec::remove(&mut stack, 1), Some(1));
+            assert!(ContiguousVec::try_insert(&mut stack, 0, 42).is_ok());
+            assert_eq!(ContiguousVec::as_slice(&stack), [42, 0, 2]);
+
+            let mut heap: Vec<u8> = Vec::new();
+            assert_eq!(push_up_to(&mut heap, 5), 5);
+            assert!(matches!(ContiguousVec::try_insert(&mut heap, 6, 42), Err(InsertError::OutOfBounds { idx: 6, len: 5, elem: 42 })));
+            assert_eq!(ContiguousVec::remove(&mut heap, 5), None);
+            ContiguousVec::clear(&mut heap);
+            assert!(ContiguousVec::is_empty(&heap));
+        }
+
+        #[test]
+        fn hybrid_vec_try_insert() {
+            let mut v: HybridVec<2, u8> = HybridVec::new();
+            assert!(v.try_insert(0, 2).is_ok());
+            assert!(v.try_insert(0, 1).is_ok());
+            assert_eq!(v.try_insert(3, 4), Err(InsertError::OutOfBounds { idx: 3, len: 2, elem: 4 }));
+            assert!(!v.spilled());
+
+            // Spills instead of running out of capacity
+            assert!(v.try_insert(2, 3).is_ok());
+            assert!(v.spilled());
+            assert_eq!(v.as_slice(), [1, 2, 3]);
+        }
+
+        #[test]
+        fn containers() {
+            let mut s: StackString<4> = StackString::new();
+            assert!(s.try_push_str("abc").is_ok());
+            assert!(s.try_push_str("de").is_err());
+            assert!(s.try_push('d').is_ok());
+            assert!(s.try_push('e').is_err());
+            assert_eq!(s.pop(), Some('d'));
+
+            let mut d: StackDeque<2, u8> = StackDeque::new();
+            assert!(d.try_push_back(2).is_ok());
+            assert!(d.try_push_front(1).is_ok());
+            assert!(d.try_push_back(3).is_err());
+            assert_eq!(d.get(2), None);
+            assert_eq!((d.pop_front(), d.pop_back(), d.pop_back()), (Some(1), Some(2), None));
+            assert!(StackDeque::<0, u8>::new().try_push_front(1).is_err());
+
+            let mut set: StackSet<1, u8> = StackSet::new();
+            assert_eq!(set.try_insert(1), Ok(true));
+            assert_eq!(set.try_insert(1), Ok(false));
+            assert!(set.try_insert(2).is_err());
+
+            let mut map: StackMap<1, u8, u8> = StackMap::new();
+            assert_eq!(map.try_insert(1, 1), Ok(None));
+            assert!(map.try_insert(2, 2).is_err());
+        }
+
+        #[test]
+        fn try_grow() {
+            let mut vec: StackVec<4, u8> = StackVec::new();
+            assert!(vec.try_resize(2, 7).is_ok());
+            assert_eq!(vec.try_resize_with(6, || 0).unwrap_err().excess(), 2);
+            assert_eq!(vec.try_push_front(1), Ok(()));
+            assert_eq!(vec.as_slice(), [1, 7, 7]);
+            assert!(vec.try_resize_with(1, || 0).is_ok());
+
+            assert!(vec.try_insert_slice(1, &[3, 4]).is_ok());
+            assert_eq!(vec.try_insert_slice(4, &[5]), Err(InsertError::OutOfBounds { idx: 4, len: 3, elem: () }));
+            assert_eq!(vec.try_insert_many(0, [8, 9]).unwrap_err().into_element().collect::<Vec<u8>>(), [8, 9]);
+            assert!(vec.try_insert_many(1, [2]).is_ok());
+            assert_eq!(vec.try_push_front(0).unwrap_err().into_element(), 0);
+            assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+            let mut other: StackVec<4, u8> = StackVec::try_from_iter([5]).unwrap();
+            assert_eq!(vec.try_append(&mut other).unwrap_err().excess(), 1);
+            assert_eq!(other.as_slice(), [5]);
+            assert!(other.try_append(&mut StackVec::<2, u8>::try_from_iter([6, 7]).unwrap()).is_ok());
+            assert_eq!(other.as_slice(), [5, 6, 7]);
+        }
+
+        #[test]
+        fn try_extend_from_within() {
+            let mut vec: StackVec<5, u8> = StackVec::try_from_iter([1, 2]).unwrap();
+            assert!(vec.try_extend_from_within(..).is_ok());
+            assert_eq!(vec.try_extend_from_within(1..3), Err(InsertError::Capacity(CapacityError::with_excess((), 5, 1))));
+            assert_eq!(vec.try_extend_from_within(2..5), Err(InsertError::OutOfBounds { idx: 5, len: 4, elem: () }));
+            assert_eq!(vec.try_extend_from_within(..=usize::MAX), Err(InsertError::OutOfBounds { idx: usize::MAX, len: 4, elem: () }));
+            assert!(vec.try_extend_from_within(3..).is_ok());
+            assert_eq!(vec.as_slice(), [1, 2, 1, 2, 2]);
+        }
+
+        #[test]
+        fn try_remove_indices() {
+            let mut vec: StackVec<5, u8> = StackVec::try_from_iter([1, 2, 3, 4, 5]).unwrap();
+            assert!(!vec.try_remove_indices(&[1, 1]));
+            assert!(!vec.try_remove_indices(&[3, 1]));
+            assert!(!vec.try_remove_indices(&[0, 5]));
+            assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+            assert!(vec.try_remove_indices(&[0, 2, 4]));
+            assert_eq!(vec.as_slice(), [2, 4]);
+        }
+
+        #[test]
+        fn try_swap_and_copy_within() {
+            use std::ops::Bound;
+
+            let mut vec: StackVec<4, u8> = StackVec::try_from_iter([1, 2, 3]).unwrap();
+            assert!(vec.try_swap(0, 2));
+            assert!(!vec.try_swap(0, 3));
+            assert_eq!(vec.as_slice(), [3, 2, 1]);
+            assert!(vec.try_copy_within(0..1, 2));
+            assert!(!vec.try_copy_within(0..1, 3));
+            assert!(!vec.try_copy_within((Bound::Included(2), Bound::Excluded(1)), 0));
+            assert!(!vec.try_copy_within(..=usize::MAX, 0));
+            assert_eq!(vec.as_slice(), [3, 2, 3]);
+        }
+
+        #[cfg(feature = "no-panic")]
+        #[test]
+        fn swap_and_copy_within() {
+            // Out-of-bounds indices leave the vec untouched instead of reaching the slice methods
+            let mut vec: StackVec<4, u8> = StackVec::try_from_iter([1, 2, 3]).unwrap();
+            vec.swap(0, 3);
+            vec.copy_within(1.., 2);
+            assert_eq!(vec.as_slice(), [1, 2, 3]);
+            vec.swap(0, 2);
+            vec.copy_within(1..2, 0);
+            assert_eq!(vec.as_slice(), [2, 2, 1]);
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER MACROS *****/
+/// Implements [`Index`] and [`IndexMut`] for a particular range.
+macro_rules! index_range_impl {
+    ($(#[$attr:meta])* $range:ty, $conv:expr) => {
+        $(#[$attr])*
+        impl<const LEN: usize, T, P, L: LenType> Index<$range> for StackVec<LEN, T, P, L> {
+            type Output = [T];
+
+            #[inline]
+            #[track_caller]
+            fn index(&self, index: $range) -> &Self::Output {
+                // Get a proper range out of this
+                let (start, end): (usize, usize) = $conv(self.len(), index);
+
+                // Create a slice
+                // SAFETY: Gotta prove two things here;
+                // - We can safely assume that the [`MaybeUninit`]s are initialized, because of our assertion for `self.len` that the first `self.len` elements are always initialized, and we made sure that the `index` is within that space; and
+                // - We can call `std::mem::transmute` because `T` and `MaybeUninit<T>` are guaranteed to have the same layout. Conditions on stuff like abusing niche values do not apply, since we don't inherently enum the MaybeUninit, and neither does slice do anything with it.
+                unsafe { std::mem::transmute(&self.data[start..end]) }
+            }
+        }
+        $(#[$attr])*
+        impl<const LEN: usize, T, P, L: LenType> IndexMut<$range> for StackVec<LEN, T, P, L> {
+            #[inline]
+            #[track_caller]
+            fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                // Get a proper range out of this
+                let (start, end): (usize, usize) = $conv(self.len(), index);
+
+                // Create a slice
+                // SAFETY: Gotta prove two things here;
+                // - We can safely assume that the [`MaybeUninit`]s are initialized, because of our assertion for `self.len` that the first `self.len` elements are always initialized, and we made sure that the `index` is within that space; and
+                // - We can call `std::mem::transmute` because `T` and `MaybeUninit<T>` are guaranteed to have the same layout. Conditions on stuff like abusing niche values do not apply, since we don't inherently enum the MaybeUninit, and neither does slice do anything with it.
+                unsafe { std::mem::transmute(&mut self.data[start..end]) }
+            }
+        }
+    };
+}
+
+
+
+
+
+/// Implements [`LenType`] for a primitive unsigned integer.
+macro_rules! len_type_impl {
+    ($t:ty) => {
+        impl sealed::Sealed for $t {}
+        impl LenType for $t {
+            const MAX: usize = if <$t>::BITS >= usize::BITS { usize::MAX } else { <$t>::MAX as usize };
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn to_usize(self) -> usize { self as usize }
+
+            #[inline]
+            fn from_usize(len: usize) -> Self { len as $t }
+        }
+    };
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Asserts a condition, meant to be called in `const` blocks to check const generics at compile time.
+///
+/// Unlike using [`assert!()`] directly, this keeps the control flow out of the `const` block, which the `generic_const_exprs` nightly feature (see the `generic-const-exprs` crate feature) does not support there.
+///
+/// # Arguments
+/// - `cond`: The condition that must hold.
+/// - `msg`: The message to panic with if it doesn't.
+///
+/// # Panics
+/// This function panics if `cond` is false, which fails compilation when called in a `const` block.
+#[inline]
+#[track_caller]
+pub(crate) const fn const_assert(cond: bool, msg: &'static str) {
+    if !cond {
+        panic!("{}", msg)
+    }
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines the error returned when a [`StackVec`] does not have enough capacity left for some operation.
+///
+/// It is used by all fallible operations on StackVecs that run out of capacity. It carries the element(s) that could not be added, so they aren't lost; for operations that don't consume anything (e.g., when cloning from a slice), `T` is simply `()`.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct CapacityError<T = ()> {
+    /// The element(s) that did not fit.
+    elem:     T,
+    /// The capacity of the StackVec that the element(s) did not fit in.
+    capacity: usize,
+    /// The number of elements that did not fit.
+    excess:   usize,
+}
+
+impl<T> CapacityError<T> {
+    /// Constructor for the CapacityError for when a single element did not fit.
+    ///
+    /// # Arguments
+    /// - `elem`: The element that did not fit.
+    /// - `capacity`: The capacity of the StackVec that the element did not fit in.
+    ///
+    /// # Returns
+    /// A new CapacityError carrying `elem`.
+    #[inline]
+    pub const fn new(elem: T, capacity: usize) -> Self { Self { elem, capacity, excess: 1 } }
+
+    /// Constructor for the CapacityError for when any number of elements did not fit.
+    ///
+    /// # Arguments
+    /// - `elem`: The element(s) that did not fit, e.g., as an iterator.
+    /// - `capacity`: The capacity of the StackVec that the elements did not fit in.
+    /// - `excess`: The number of elements that did not fit.
+    ///
+    /// # Returns
+    /// A new CapacityError carrying `elem`.
+    #[inline]
+    pub const fn with_excess(elem: T, capacity: usize, excess: usize) -> Self { Self { elem, capacity, excess } }
+
+    /// Returns a reference to the element(s) that did not fit.
+    #[inline]
+    pub const fn element(&self) -> &T { &self.elem }
+
+    /// Consumes the CapacityError to get back the element(s) that did not fit.
+    #[inline]
+    pub fn into_element(self) -> T { self.elem }
+
+    /// Returns the capacity of the StackVec that the element(s) did not fit in.
+    #[inline]
+    pub const fn capacity(&self) -> usize { self.capacity }
+
+    /// Returns the number of elements that did not fit.
+    #[inline]
+    pub const fn excess(&self) -> usize { self.excess }
+
+    /// Discards the element(s) carried by this CapacityError.
+    ///
+    /// This is useful to pass it on as an error that does not depend on `T` (e.g., to box it).
+    ///
+    /// # Returns
+    /// A CapacityError with the same capacity and excess, but carrying `()`.
+    #[inline]
+    pub fn simplify(self) -> CapacityError { CapacityError { elem: (), capacity: self.capacity, excess: self.excess } }
+}
+
+impl<T> Debug for CapacityError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        f.debug_struct("CapacityError").field("capacity", &self.capacity).field("excess", &self.excess).finish_non_exhaustive()
+    }
+}
+impl<T> Display for CapacityError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        write!(f, "Insufficient capacity in StackVec of capacity {} ({} element(s) did not fit)", self.capacity, self.excess)
+    }
+}
+impl<T> Error for CapacityError<T> {}
+
+/// Defines the error returned when inserting an element into a [`StackVec`] fails.
+///
+/// Like [`CapacityError`], it carries the element that could not be inserted, so it isn't lost.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub enum InsertError<T> {
+    /// The StackVec did not have enough capacity left.
+    Capacity(CapacityError<T>),
+    /// The index to insert at was out-of-bounds.
+    OutOfBounds {
+        /// The index that was out-of-bounds.
+        idx:  usize,
+        /// The length of the StackVec at the time.
+        len:  usize,
+        /// The element that could not be inserted.
+        elem: T,
+    },
+}
+
+impl<T> InsertError<T> {
+    /// Returns a reference to the element that could not be inserted.
+    #[inline]
+    pub const fn element(&self) -> &T {
+        match self {
+            Self::Capacity(err) => err.element(),
+            Self::OutOfBounds { elem, .. } => elem,
+        }
+    }
+
+    /// Consumes the InsertError to get back the element that could not be inserted.
+    #[inline]
+    pub fn into_element(self) -> T {
+        match self {
+            Self::Capacity(err) => err.into_element(),
+            Self::OutOfBounds { elem, .. } => elem,
+        }
+    }
+
+    /// Discards the element(s) carried by this InsertError.
+    ///
+    /// This is useful to pass it on as an error that does not depend on `T` (e.g., to box it).
+    ///
+    /// # Returns
+    /// An InsertError of the same kind, but carrying `()`.
+    #[inline]
+    pub fn simplify(self) -> InsertError<()> {
+        match self {
+            Self::Capacity(err) => InsertError::Capacity(err.simplify()),
+            Self::OutOfBounds { idx, len, .. } => InsertError::OutOfBounds { idx, len, elem: () },
+        }
+    }
+}
+
+impl<T> Debug for InsertError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        match self {
+            Self::Capacity(err) => f.debug_tuple("Capacity").field(err).finish(),
+            Self::OutOfBounds { idx, len, .. } => f.debug_struct("OutOfBounds").field("idx", idx).field("len", len).finish_non_exhaustive(),
+        }
+    }
+}
+impl<T> Display for InsertError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        match self {
+            Self::Capacity(err) => write!(f, "{err}"),
+            Self::OutOfBounds { idx, len, .. } => write!(f, "Index {idx} is out-of-bounds for StackVec of length {len}"),
+        }
+    }
+}
+impl<T> Error for InsertError<T> {}
+
+
+
+
+
+/***** POLICIES *****/
+/// Decides what a [`StackVec`] does when pushing to it while it's full.
+///
+/// It is given as the (optional) third type parameter of a StackVec, so that application code can pick the semantics once instead of remembering to call the right method everywhere. The following policies are provided:
+/// - [`PanicOnFull`] (default): panics, like a [`Vec`] would if it runs out of memory;
+/// - [`DropOnFull`]: saturates by dropping the new element;
+/// - [`OverwriteOldest`]: evicts the first element to make space for the new one; and
+/// - [`ErrorOnFull`]: returns a [`CapacityError`].
+pub trait OverflowPolicy: Sized {
+    /// The type returned by [`StackVec::push()`] under this policy.
+    type Pushed<T>;
+    /// The type returned by [`StackVec::extend()`] under this policy.
+    type Extended<T>;
+
+    /// Pushes a new element to the end of the given StackVec, handling it being full according to this policy.
+    ///
+    /// # Arguments
+    /// - `vec`: The StackVec to push to.
+    /// - `elem`: The new element to push.
+    ///
+    /// # Returns
+    /// Whatever makes sense for this policy.
+    fn push<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elem: T) -> Self::Pushed<T>;
+
+    /// Extends the given StackVec with any number of new elements, handling it becoming full according to this policy.
+    ///
+    /// # Arguments
+    /// - `vec`: The StackVec to extend.
+    /// - `elems`: Something [iterable](IntoIterator) that generates the elements to append.
+    ///
+    /// # Returns
+    /// Whatever makes sense for this policy.
+    fn extend<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elems: impl IntoIterator<Item = T>) -> Self::Extended<T>;
+}
+
+/// An [`OverflowPolicy`] that panics when pushing to a full [`StackVec`].
+///
+/// This is the default policy. When the `no-panic` feature is enabled, it is not a valid policy to push with, and one of the other policies must be chosen instead.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PanicOnFull;
+#[cfg(not(feature = "no-panic"))]
+impl OverflowPolicy for PanicOnFull {
+    type Extended<T> = ();
+    type Pushed<T> = ();
+
+    #[inline]
+    #[track_caller]
+    fn push<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elem: T) -> Self::Pushed<T> { vec.push_or_panic(elem) }
+
+    #[inline]
+    #[track_caller]
+    fn extend<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elems: impl IntoIterator<Item = T>) -> Self::Extended<T> {
+        vec.extend_or_panic(elems)
+    }
+}
+
+/// An [`OverflowPolicy`] that saturates a full [`StackVec`], dropping any new elements.
+///
+/// Pushing returns the rejected element (if any), so it can still be recovered.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DropOnFull;
+impl OverflowPolicy for DropOnFull {
+    type Extended<T> = ();
+    type Pushed<T> = Option<T>;
+
+    #[inline]
+    fn push<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elem: T) -> Self::Pushed<T> { vec.push_within_capacity(elem).err() }
+
+    #[inline]
+    fn extend<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elems: impl IntoIterator<Item = T>) -> Self::Extended<T> {
+        // Any elements that don't fit are simply dropped
+        let _ = vec.extend_until_full(elems);
+    }
+}
+
+/// An [`OverflowPolicy`] that evicts the first (i.e., oldest) element of a full [`StackVec`] to make space for new elements.
+///
+/// Pushing returns the evicted element (if any). See [`StackVec::push_rotate()`] for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OverwriteOldest;
+impl OverflowPolicy for OverwriteOldest {
+    type Extended<T> = ();
+    type Pushed<T> = Option<T>;
+
+    #[inline]
+    fn push<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elem: T) -> Self::Pushed<T> { vec.push_rotate(elem) }
+
+    #[inline]
+    fn extend<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elems: impl IntoIterator<Item = T>) -> Self::Extended<T> {
+        for elem in elems {
+            vec.push_rotate(elem);
+        }
+    }
+}
+
+/// An [`OverflowPolicy`] that returns a [`CapacityError`] when pushing to a full [`StackVec`].
+///
+/// When extending, the StackVec is extended until it is full, after which the first element that did not fit is returned in the error. The iterator is not advanced any further.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ErrorOnFull;
+impl OverflowPolicy for ErrorOnFull {
+    type Extended<T> = Result<(), CapacityError<T>>;
+    type Pushed<T> = Result<(), CapacityError<T>>;
+
+    #[inline]
+    fn push<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elem: T) -> Self::Pushed<T> { vec.try_push(elem) }
+
+    #[inline]
+    fn extend<const LEN: usize, T, L: LenType>(vec: &mut StackVec<LEN, T, Self, L>, elems: impl IntoIterator<Item = T>) -> Self::Extended<T> {
+        for elem in elems {
+            vec.try_push(elem)?;
+        }
+        Ok(())
+    }
+}
+
+
+
+
+
+/***** LENGTH TYPES *****/
+/// Prevents [`LenType`] from being implemented outside of this crate.
+mod sealed {
+    /// Implemented only for the types that may be used as a [`LenType`](super::LenType).
+    pub trait Sealed {}
+}
+
+/// The type that a [`StackVec`] uses to store its length.
+///
+/// It is implemented for [`u8`], [`u16`], [`u32`] and [`usize`] (the default). Using a smaller type shrinks the StackVec, but limits its capacity to [`LenType::MAX`].
+pub trait LenType: 'static + Copy + Debug + Default + Eq + Hash + Ord + sealed::Sealed {
+    /// The largest length that this type can represent.
+    const MAX: usize;
+    /// A length of zero.
+    const ZERO: Self;
+
+    /// Converts a length of this type to a [`usize`].
+    ///
+    /// # Returns
+    /// The same length, as a [`usize`].
+    fn to_usize(self) -> usize;
+
+    /// Converts a [`usize`] to a length of this type.
+    ///
+    /// # Arguments
+    /// - `len`: The length to convert. It must not exceed [`Self::MAX`](LenType::MAX), or else it is truncated.
+    ///
+    /// # Returns
+    /// The same length, as this type.
+    fn from_usize(len: usize) -> Self;
+}
+len_type_impl!(u8);
+len_type_impl!(u16);
+len_type_impl!(u32);
+len_type_impl!(usize);
+
+/// Asserts that `L` is the unsigned integer of its size, which [`len_to_usize()`] and [`usize_to_len()`] rely on to reinterpret it.
+///
+/// This holds because [`LenType`] is sealed and only implemented (by `len_type_impl!()`) for [`u8`], [`u16`], [`u32`] and [`usize`]. Called in `const` blocks, this fails compilation if an impl is ever added that breaks it.
+#[inline]
+const fn assert_unsigned_len_type<L: LenType>() {
+    let bits: u32 = 8 * std::mem::size_of::<L>() as u32;
+    let max: usize = if bits >= usize::BITS { usize::MAX } else { (1 << bits) - 1 };
+    let align: usize = match bits {
+        8 => std::mem::align_of::<u8>(),
+        16 => std::mem::align_of::<u16>(),
+        32 => std::mem::align_of::<u32>(),
+        _ => std::mem::align_of::<usize>(),
+    };
+    const_assert(matches!(bits, 8 | 16 | 32) || bits == usize::BITS, "LenType must be implemented only for u8, u16, u32 and usize");
+    const_assert(std::mem::align_of::<L>() == align, "LenType must have the alignment of the unsigned integer of its size");
+    const_assert(L::MAX == max, "LenType::MAX must be that of the unsigned integer of its size");
+}
+
+/// Converts a [`LenType`] to a [`usize`] in `const` contexts, where we cannot call [`LenType::to_usize()`].
+///
+/// # Arguments
+/// - `len`: The length to convert.
+///
+/// # Returns
+/// The same length, as a [`usize`].
+#[inline]
+const fn len_to_usize<L: LenType>(len: &L) -> usize {
+    const { assert_unsigned_len_type::<L>() };
+    let ptr: *const L = len;
+    // SAFETY: We asserted above that `L` is the unsigned integer of its size (i.e., one of the types `len_type_impl!()` implements the sealed `LenType` for), so reading it as that integer merely reinterprets the same value. The last arm is only reached for the size of `usize`, as the assertion rules out any other.
+    unsafe {
+        match std::mem::size_of::<L>() {
+            1 => *(ptr as *const u8) as usize,
+            2 => *(ptr as *const u16) as usize,
+            4 => *(ptr as *const u32) as usize,
+            _ => *(ptr as *const usize),
+        }
+    }
+}
+
+
+
+
+
+/// Converts a [`usize`] to a [`LenType`] in `const` contexts, where we cannot call [`LenType::from_usize()`].
+///
+/// # Arguments
+/// - `len`: The length to convert. It must not exceed [`LenType::MAX`], or else it is truncated.
+///
+/// # Returns
+/// The same length, as an `L`.
+#[inline]
+const fn usize_to_len<L: LenType>(len: usize) -> L {
+    const { assert_unsigned_len_type::<L>() };
+    let mut res: L = L::ZERO;
+    let ptr: *mut L = &mut res;
+    // SAFETY: We asserted above that `L` is the unsigned integer of its size (i.e., one of the types `len_type_impl!()` implements the sealed `LenType` for), so writing it as that integer produces a valid `L`. The last arm is only reached for the size of `usize`, as the assertion rules out any other.
+    unsafe {
+        match std::mem::size_of::<L>() {
+            1 => *(ptr as *mut u8) = len as u8,
+            2 => *(ptr as *mut u16) = len as u16,
+            4 => *(ptr as *mut u32) = len as u32,
+            _ => *(ptr as *mut usize) = len,
+        }
+    }
+    res
+}
+
+
+
+
+
+/***** ITERATORS *****/
+/// Iterates over a [`StackVec`] by ownership.
+pub struct IntoIter<const LEN: usize, T, L: LenType = usize> {
+    /// Some [`StackVec`] that we iterate over.
+    vec: StackVec<LEN, T, PanicOnFull, L>,
+    /// The current index of iteration.
+    i:   usize,
+    /// The current end of the iteration. Exclusive (so `0` means nothing).
+    end: usize,
+}
+impl<const LEN: usize, T, L: LenType> IntoIter<LEN, T, L> {
+    /// Returns the elements that haven't been yielded yet as a slice.
+    ///
+    /// # Returns
+    /// A slice of the remaining elements, in order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The elements from `self.i` up to `self.end` are initialized, as they are within the original length and have not been yielded yet.
+        unsafe { std::slice::from_raw_parts(self.vec.as_ptr().add(self.i), self.end - self.i) }
+    }
+
+    /// Returns the elements that haven't been yielded yet as a mutable slice.
+    ///
+    /// # Returns
+    /// A mutable slice of the remaining elements, in order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The elements from `self.i` up to `self.end` are initialized, as they are within the original length and have not been yielded yet.
+        unsafe { std::slice::from_raw_parts_mut(self.vec.as_mut_ptr().add(self.i), self.end - self.i) }
+    }
+
+    /// Drops the next elements in the iterator at once, without yielding them.
+    ///
+    /// # Arguments
+    /// - `n`: The number of elements to drop. If this is more than the remaining elements, all of them are dropped.
+    ///
+    /// # Returns
+    /// The number of elements that were actually dropped.
+    #[inline]
+    fn drop_front(&mut self, n: usize) -> usize {
+        let n: usize = n.min(self.end - self.i);
+        let start: usize = self.i;
+        // Move past them first, so a panicking destructor doesn't cause them to be dropped again
+        self.i += n;
+        // SAFETY: The `n` elements after `start` are initialized and have not been yielded, and we just made sure we never touch them again.
+        unsafe { std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.vec.as_mut_ptr().add(start), n)) };
+        n
+    }
+}
+
+impl<const LEN: usize, T, L: LenType> Default for IntoIter<LEN, T, L> {
+    /// Creates an empty iterator.
+    #[inline]
+    fn default() -> Self { Self { vec: StackVec::default(), i: 0, end: 0 } }
+}
+impl<const LEN: usize, T: Clone, L: LenType> Clone for IntoIter<LEN, T, L> {
+    /// Clones only the elements that haven't been yielded yet.
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut vec: StackVec<LEN, T, PanicOnFull, L> = StackVec::new();
+        // NOTE: Can't fail, as we never have more than `LEN` remaining elements
+        let _ = vec.try_extend_from_slice(self.as_slice());
+        let end: usize = vec.len();
+        Self { vec, i: 0, end }
+    }
+}
+impl<const LEN: usize, T: Debug, L: LenType> Debug for IntoIter<LEN, T, L> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_tuple("IntoIter").field(&self.as_slice()).finish() }
+}
+impl<const LEN: usize, T, L: LenType> Drop for IntoIter<LEN, T, L> {
+    #[inline]
+    fn drop(&mut self) {
+        // Drop any remaining elements
+        self.drop_front(self.end - self.i);
+
+        // Now manually edit the length of the stackvec to prevent it dropping anything
+        self.vec.len = L::ZERO;
+    }
+}
+
+impl<const LEN: usize, T, L: LenType> Iterator for IntoIter<LEN, T, L> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i < self.end {
+            // Get the element
+            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
+            std::mem::swap(&mut res, &mut self.vec.data[self.i]);
+            self.i += 1;
+
+            // SAFETY: This is OK because of the `self.len` assertion and `i` is below that length (because `end` is below that length).
+            Some(unsafe { res.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.end - self.i, Some(self.end - self.i)) }
+
+    #[inline]
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.end - self.i
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Drop the skipped elements in one go
+        self.drop_front(n);
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item>
+    where
+        Self: Sized,
+    {
+        // The others are dropped in bulk when we are
+        self.next_back()
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc: B = init;
+        while self.i < self.end {
+            // SAFETY: This is OK because of the `self.len` assertion and `i` is below that length (because `end` is below that length). We move past it immediately, so it's never read again.
+            let elem: T = unsafe { self.vec.data[self.i].assume_init_read() };
+            self.i += 1;
+            acc = f(acc, elem);
+        }
+        acc
+    }
+}
+impl<const LEN: usize, T, L: LenType> DoubleEndedIterator for IntoIter<LEN, T, L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.end > self.i {
+            // Get the element
+            self.end -= 1;
+            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
+            std::mem::swap(&mut res, &mut self.vec.data[self.end]);
+
+            // SAFETY: This is OK because of the `self.len` assertion and `end` is below that length (given at construction), and at or above `i` (so not yielded yet).
+            Some(unsafe { res.assume_init() })
+        } else {
+            None
+        }
+    }
+}
+impl<const LEN: usize, T, L: LenType> ExactSizeIterator for IntoIter<LEN, T, L> {
+    #[inline]
+    fn len(&self) -> usize { self.end - self.i }
+}
+impl<const LEN: usize, T, L: LenType> FusedIterator for IntoIter<LEN, T, L> {}
+
+/// Iterates over a [`StackVec`] by ownership, in owned chunks of (at most) `M` elements.
+///
+/// Returned by [`StackVec::into_chunks()`].
+#[derive(Clone, Debug)]
+pub struct IntoChunks<const LEN: usize, const M: usize, T, L: LenType = usize> {
+    /// The elements that we have not yet yielded.
+    iter: IntoIter<LEN, T, L>,
+}
+impl<const LEN: usize, const M: usize, T, L: LenType> Iterator for IntoChunks<LEN, M, T, L> {
+    type Item = StackVec<M, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() > 0 { Some(StackVec::from_iter_truncated(self.iter.by_ref())) } else { None }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n: usize = self.iter.len().div_ceil(M);
+        (n, Some(n))
+    }
+}
+impl<const LEN: usize, const M: usize, T, L: LenType> ExactSizeIterator for IntoChunks<LEN, M, T, L> {}
+impl<const LEN: usize, const M: usize, T, L: LenType> FusedIterator for IntoChunks<LEN, M, T, L> {}
+
+/// Iterates over the elements of a [`StackVec`] that match some predicate, removing them from it.
+///
+/// Elements that do not match the predicate are kept in the StackVec, in their original order. If the iterator is dropped before it is exhausted, all unvisited elements are kept as well.
+///
+/// Created by [`StackVec::extract_if()`].
+pub struct ExtractIf<'v, const LEN: usize, T, F, P = PanicOnFull, L: LenType = usize> {
+    /// The [`StackVec`] that we extract from.
+    vec:  &'v mut StackVec<LEN, T, P, L>,
+    /// The predicate that decides which elements to extract.
+    pred: F,
+    /// The index of the next element to examine.
+    i:    usize,
+    /// The number of elements extracted so far.
+    del:  usize,
+    /// The length of the StackVec before we started extracting.
+    len:  usize,
+}
+
+impl<'v, const LEN: usize, T, F, P, L: LenType> Drop for ExtractIf<'v, LEN, T, F, P, L> {
+    #[inline]
+    fn drop(&mut self) {
+        // Move all unvisited elements back to close the gap left by the extracted ones
+        if self.del > 0 {
+            for i in self.i..self.len {
+                // SAFETY: Element `i` is initialized (it's unvisited), and `i - self.del` has been moved out of. Swapping restores the first `self.len - self.del` elements being initialized.
+                self.vec.data.swap(i - self.del, i);
+            }
+        }
+
+        // Now we can restore the length
+        // SAFETY: This upholds our `self.len` assertion, because we just compacted the remaining elements to the front.
+        self.vec.len = L::from_usize(self.len - self.del);
+    }
+}
+
+impl<'v, const LEN: usize, T, F: FnMut(&mut T) -> bool, P, L: LenType> Iterator for ExtractIf<'v, LEN, T, F, P, L> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.i < self.len {
+            // Decide whether to extract this element
+            // SAFETY: This is OK because `i` is below the original length, and we haven't touched any elements at or after `i` yet.
+            let extract: bool = (self.pred)(unsafe { self.vec.data[self.i].assume_init_mut() });
+            let i: usize = self.i;
+            self.i += 1;
+
+            if extract {
+                // Move the element out, leaving a gap behind
+                self.del += 1;
+                // SAFETY: The element is initialized (see above), and we count it as a gap from now on so it's never read again.
+                return Some(unsafe { self.vec.data[i].assume_init_read() });
+            } else if self.del > 0 {
+                // Move the element back to close the gap
+                // SAFETY: `i - self.del` is a gap left by an extracted element, so swapping moves the kept element to its new place.
+                self.vec.data.swap(i - self.del, i);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, Some(self.len - self.i)) }
+}
+impl<'v, const LEN: usize, T, F: FnMut(&mut T) -> bool, P, L: LenType> FusedIterator for ExtractIf<'v, LEN, T, F, P, L> {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a non-reallocatable, but resizeable, [`Vec`]-like structure that lives in the stack.
+///
+/// This makes allocating it pretty cheap. Even better, basically all functions on it can be `const`.
+///
+/// # `const` usage
+/// StackVecs can be built and changed in `const` items and `static` initializers using [`Self::new()`](StackVec::new()), [`Self::from_array()`](StackVec::from_array()), [`Self::try_push()`](StackVec::try_push()), [`Self::push_within_capacity()`](StackVec::push_within_capacity()) and [`Self::pop()`](StackVec::pop()), and, for [`Copy`] elements, [`Self::clear_copy()`](StackVec::clear_copy()).
+///
+/// [`Self::push()`](StackVec::push()) is not `const`, because it calls into the [`OverflowPolicy`], and neither is [`Self::clear()`](StackVec::clear()), because it may have to drop elements.
+///
+/// What [`Self::push()`](StackVec::push()) and [`Self::extend()`](StackVec::extend()) do when the StackVec is full is decided by the [`OverflowPolicy`] `P`. By default, they panic (see [`PanicOnFull`]).
+///
+/// **Editor's note**: Making `StackVec` [`Copy`] requires conditional [`Drop`] to be safe, which is not possible currently. Aww man that's sad. Use a [`CopyStackVec`] instead if you need it.
+///
+/// # Layout
+/// By default, the layout of the StackVec is unspecified. If the `repr-c` feature is enabled, it is `#[repr(C)]` instead, with the following fields in order:
+/// 1. `data: [MaybeUninit<T>; LEN]`, of which the first `len` elements are initialized; and
+/// 2. `len: L`, the number of initialized elements.
+///
+/// With the default `usize` length type, this matches the C struct `struct { T data[LEN]; size_t len; }` (for `LEN > 0`), so that it can be shared across an FFI boundary. The policy `P` is zero-sized and does not influence the layout.
+///
+/// # Length type
+/// The length is stored as a [`LenType`] `L`, which is [`usize`] by default. Choosing a smaller type (e.g., `StackVec<16, u8, PanicOnFull, u8>`) makes the StackVec more compact, as long as `LEN` fits in it. Constructing a StackVec whose `LEN` exceeds [`LenType::MAX`] fails to compile.
+///
+/// The integrations with other crates (e.g., `serde` or `heapless`) are only implemented for the default `usize` length type.
+#[cfg_attr(feature = "repr-c", repr(C))]
+pub struct StackVec<const LEN: usize, T, P = PanicOnFull, L: LenType = usize> {
+    /// The data array that we wrap.
+    data:   [MaybeUninit<T>; LEN],
+    /// The current number of initialized elements.
+    ///
+    /// We implement the StackVec such that is upholds the following assertion: the first `len` elements of `data` are initialized.
+    len:    L,
+    /// The [`OverflowPolicy`] that decides what happens when pushing to a full StackVec.
+    policy: PhantomData<P>,
+}
+
+impl<const LEN: usize, T, P, L: LenType> Default for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Constructor for the StackVec that initializes it as empty.
+    ///
+    /// Note that, by design, StackVecs always have capacity `LEN`.
+    ///
+    /// # Returns
+    /// A new StackVec with no elements in it.
+    #[inline]
+    pub const fn new() -> Self {
+        // The length type must be able to count all elements
+        const { const_assert(LEN <= L::MAX, "Cannot create a StackVec with a capacity that its length type cannot represent") };
+
+        Self {
+            // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still.
+            data:   unsafe { MaybeUninit::uninit().assume_init() },
+            len:    L::ZERO,
+            policy: PhantomData,
+        }
+    }
+
+    /// Constructor for the StackVec that takes over the elements of an array.
+    ///
+    /// This is equivalent to converting the array with [`From`], except that it is `const`. Refuses to compile if `LEN2` exceeds `LEN`.
+    ///
+    /// # Arguments
+    /// - `array`: The array of elements to move into the StackVec.
+    ///
+    /// # Returns
+    /// A new StackVec with the elements of `array`, in order.
+    #[inline]
+    pub const fn from_array<const LEN2: usize>(array: [T; LEN2]) -> Self {
+        // Refuse to compile for arrays that don't fit
+        const { const_assert(LEN2 <= LEN, "Cannot create a StackVec from an array that is larger than its capacity") };
+
+        let mut stack: Self = Self::new();
+        let array: ManuallyDrop<[T; LEN2]> = ManuallyDrop::new(array);
+        // SAFETY: The array has `LEN2` initialized elements, which fit in our buffer as asserted above. Because we wrapped it in a `ManuallyDrop`, the elements are moved instead of duplicated.
+        unsafe { std::ptr::copy_nonoverlapping(&array as *const ManuallyDrop<[T; LEN2]> as *const T, stack.data.as_mut_ptr() as *mut T, LEN2) };
+        // SAFETY: We just initialized the first `LEN2` elements.
+        stack.len = usize_to_len(LEN2);
+        stack
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Constructor for the StackVec that generates the given number of elements.
+    ///
+    /// # Arguments
+    /// - `len`: The number of elements to generate.
+    /// - `f`: A closure that is called with the index of every element, in order, to generate it.
+    ///
+    /// # Returns
+    /// A new StackVec with `len` generated elements.
+    ///
+    /// # Panics
+    /// This function panics if `len` exceeds the capacity of the StackVec. In that case, `f` is never called.
+    #[inline]
+    #[track_caller]
+    pub fn from_fn(len: usize, f: impl FnMut(usize) -> T) -> Self {
+        match Self::try_from_fn(len, f) {
+            Ok(stack) => stack,
+            Err(_) => panic!("Cannot generate {len} elements in StackVec of capacity {LEN}"),
+        }
+    }
+
+    /// Constructor for the StackVec that generates the given number of elements, returning an error if they don't fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::from_fn()`](StackVec::from_fn()).
+    ///
+    /// # Arguments
+    /// - `len`: The number of elements to generate.
+    /// - `f`: A closure that is called with the index of every element, in order, to generate it.
+    ///
+    /// # Returns
+    /// A new StackVec with `len` generated elements.
+    ///
+    /// # Errors
+    /// If `len` exceeds the capacity of the StackVec, a [`CapacityError`] is returned. `f` is never called in that case.
+    #[inline]
+    pub fn try_from_fn(len: usize, mut f: impl FnMut(usize) -> T) -> Result<Self, CapacityError> {
+        if len > LEN {
+            return Err(CapacityError::with_excess((), LEN, len - LEN));
+        }
+        let mut stack: Self = Self::new();
+        for i in 0..len {
+            stack.data[i].write(f(i));
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized. If `f` panics, the elements generated so far are dropped.
+            stack.len = L::from_usize(i + 1);
+        }
+        Ok(stack)
+    }
+
+    /// Constructor for the StackVec that collects it from an iterator, returning an error if it yields too many elements.
+    ///
+    /// This is the non-panicking counterpart of [`FromIterator::from_iter()`].
+    ///
+    /// # Arguments
+    /// - `iter`: Something [iterable](IntoIterator) that generates the elements to collect.
+    ///
+    /// # Returns
+    /// A new StackVec with all the elements yielded by `iter`, in order.
+    ///
+    /// # Errors
+    /// If `iter` yields more than `LEN` elements, a [`CapacityError`] is returned that carries the first element that did not fit. The iterator is not advanced beyond that element, and any elements collected so far are dropped.
+    #[inline]
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T>> {
+        let mut stack: Self = Self::new();
+        for elem in iter {
+            stack.try_push(elem)?;
+        }
+        Ok(stack)
+    }
+
+    /// Constructor for the StackVec that collects it from an iterator, silently stopping once it is full.
+    ///
+    /// The iterator is not advanced beyond the first `LEN` elements. Use [`Self::from_iter_truncated_counted()`](StackVec::from_iter_truncated_counted()) to find out how many elements were skipped.
+    ///
+    /// # Arguments
+    /// - `iter`: Something [iterable](IntoIterator) that generates the elements to collect.
+    ///
+    /// # Returns
+    /// A new StackVec with (at most) the first `LEN` elements yielded by `iter`, in order.
+    #[inline]
+    pub fn from_iter_truncated<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack: Self = Self::new();
+        // Don't advance the iterator beyond what we need
+        let _ = stack.extend_until_full(iter);
+        stack
+    }
+
+    /// Constructor for the StackVec that collects it from an iterator, skipping any elements that do not fit.
+    ///
+    /// Unlike [`Self::from_iter_truncated()`](StackVec::from_iter_truncated()), this exhausts the iterator to count the skipped elements.
+    ///
+    /// # Arguments
+    /// - `iter`: Something [iterable](IntoIterator) that generates the elements to collect.
+    ///
+    /// # Returns
+    /// A tuple of a new StackVec with (at most) the first `LEN` elements yielded by `iter`, in order, and the number of elements that were skipped.
+    #[inline]
+    pub fn from_iter_truncated_counted<I: IntoIterator<Item = T>>(iter: I) -> (Self, usize) {
+        let mut stack: Self = Self::new();
+        let rest: I::IntoIter = stack.extend_until_full(iter);
+        (stack, rest.count())
+    }
+
+    /// Constructor for the StackVec that builds it from a raw backing array.
+    ///
+    /// This is useful if the array has been initialized manually, e.g., when decoding it from some wire format.
+    ///
+    /// # Arguments
+    /// - `data`: The backing array of (partially) initialized elements.
+    /// - `len`: The number of elements in `data` that are initialized.
+    ///
+    /// # Returns
+    /// A new StackVec that takes ownership of the first `len` elements in `data`.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `len` is at most `LEN`; and
+    /// - the first `len` elements of `data` are initialized.
+    #[inline]
+    pub const unsafe fn from_parts(data: [MaybeUninit<T>; LEN], len: usize) -> Self {
+        // The length type must be able to count all elements
+        const { const_assert(LEN <= L::MAX, "Cannot create a StackVec with a capacity that its length type cannot represent") };
+
+        debug_assert!(len <= LEN, "Cannot create StackVec of capacity LEN with more than LEN elements");
+        // SAFETY: The caller promises this upholds our `self.len` assertion.
+        Self { data, len: usize_to_len(len), policy: PhantomData }
+    }
+
+    /// Converts this StackVec into one with another [`OverflowPolicy`].
+    ///
+    /// This is free, as the policy only exists at the type level.
+    ///
+    /// # Returns
+    /// A new StackVec with the same elements, but policy `P2`.
+    #[inline]
+    pub fn with_policy<P2>(self) -> StackVec<LEN, T, P2, L> {
+        // Prevent ourselves from dropping the elements we're about to move
+        let this: ManuallyDrop<Self> = ManuallyDrop::new(self);
+        // SAFETY: We move the data array out of `this`, which is never touched again (nor dropped).
+        let data: [MaybeUninit<T>; LEN] = unsafe { std::ptr::read(&this.data) };
+        // SAFETY: This upholds the `self.len` assertion for the new vec, because we moved the same `data` to it.
+        StackVec { data, len: this.len, policy: PhantomData }
+    }
+
+    /// Moves the elements in this StackVec to one with a larger (or the same) capacity.
+    ///
+    /// Refuses to compile if `LEN2` is smaller than `LEN`. See [`Self::try_into_smaller()`](StackVec::try_into_smaller()) for a fallible version that works for any capacity.
+    ///
+    /// # Returns
+    /// A new StackVec of capacity `LEN2` with the same elements, in the same order.
+    #[inline]
+    pub fn into_larger<const LEN2: usize>(self) -> StackVec<LEN2, T, P, L> {
+        // Refuse to compile for capacities that may not fit
+        const { const_assert(LEN <= LEN2, "Cannot move a StackVec into one with a smaller capacity") };
+
+        // SAFETY: We have at most `LEN` elements, which we asserted fits in `LEN2`.
+        unsafe { self.move_into_unchecked() }
+    }
+
+    /// Moves the elements in this StackVec to one with another capacity, returning an error if they don't fit.
+    ///
+    /// Unlike [`Self::into_larger()`](StackVec::into_larger()), this only checks the current length, so `LEN2` may be smaller than `LEN`.
+    ///
+    /// # Returns
+    /// A new StackVec of capacity `LEN2` with the same elements, in the same order.
+    ///
+    /// # Errors
+    /// If this StackVec has more than `LEN2` elements, a [`CapacityError`] is returned that carries it, untouched.
+    #[inline]
+    pub fn try_into_smaller<const LEN2: usize>(self) -> Result<StackVec<LEN2, T, P, L>, CapacityError<Self>> {
+        let len: usize = self.len();
+        if len > LEN2 {
+            return Err(CapacityError::with_excess(self, LEN2, len - LEN2));
+        }
+        // SAFETY: We just checked that our elements fit in `LEN2`.
+        Ok(unsafe { self.move_into_unchecked() })
+    }
+
+    /// Moves the elements in this StackVec to one with another capacity, without checking if they fit.
+    ///
+    /// # Returns
+    /// A new StackVec of capacity `LEN2` with the same elements, in the same order.
+    ///
+    /// # Safety
+    /// The caller must ensure that the StackVec has at most `LEN2` elements.
+    #[inline]
+    unsafe fn move_into_unchecked<const LEN2: usize>(mut self) -> StackVec<LEN2, T, P, L> {
+        let len: usize = self.len();
+        let mut res: StackVec<LEN2, T, P, L> = StackVec::new();
+        // SAFETY: The buffers are distinct, the first `len` elements of ours are initialized (as per our assertion), and the caller promises they fit in `res`.
+        unsafe { std::ptr::copy_nonoverlapping(self.as_ptr(), res.as_mut_ptr(), len) };
+        // SAFETY: The elements have been moved to `res`, so we must forget about them to not drop them twice.
+        self.len = L::ZERO;
+        // SAFETY: We just initialized the first `len` elements of `res`.
+        res.len = L::from_usize(len);
+        res
+    }
+
+    /// Transforms every element in the StackVec, keeping them in a StackVec of the same capacity.
+    ///
+    /// This is the equivalent of [`array::map()`](https://doc.rust-lang.org/std/primitive.array.html#method.map). Unlike collecting from an iterator, it does not check the capacity for every element, as there can never be more elements than before.
+    ///
+    /// # Arguments
+    /// - `f`: A closure that is called for every element, in order, to transform it.
+    ///
+    /// # Returns
+    /// A new StackVec with the transformed elements, in the same order.
+    #[inline]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> StackVec<LEN, U, P, L> {
+        let mut res: StackVec<LEN, U, P, L> = StackVec::new();
+        for elem in self {
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` elements to transform
+            res.data[res.len()].write(f(elem));
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized. If `f` panics, the elements transformed so far are dropped by `res`, and the others by the iterator.
+            res.len = L::from_usize(res.len() + 1);
+        }
+        res
+    }
+
+    /// Pairs up the elements of this StackVec with those of another one of the same capacity.
+    ///
+    /// Like [`Iterator::zip()`], this stops at the shorter of the two; any remaining elements of the longer one are dropped. Unlike collecting from an iterator, it does not check the capacity for every pair.
+    ///
+    /// # Arguments
+    /// - `other`: The StackVec (of any policy or length type) whose elements to pair ours with.
+    ///
+    /// # Returns
+    /// A new StackVec with tuples of our elements and those of `other`, in order. See [`Self::unzip()`](StackVec::unzip()) for the reverse.
+    #[inline]
+    pub fn zip<U, P2, L2: LenType>(self, other: StackVec<LEN, U, P2, L2>) -> StackVec<LEN, (T, U), P, L> {
+        let mut res: StackVec<LEN, (T, U), P, L> = StackVec::new();
+        for pair in self.into_iter().zip(other) {
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` pairs
+            res.data[res.len()].write(pair);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            res.len = L::from_usize(res.len() + 1);
+        }
+        res
+    }
+
+    /// Splits the elements of the StackVec into two StackVecs of the same capacity according to a predicate.
+    ///
+    /// Unlike [`Iterator::partition()`], this does not check the capacity for every element, as neither half can have more elements than this StackVec.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that is called for every element, in order, to decide in which half it goes.
+    ///
+    /// # Returns
+    /// A tuple of a StackVec with the elements for which `pred` returned true, and a StackVec with the others. Both preserve the relative order of the elements.
+    #[inline]
+    pub fn partition(self, mut pred: impl FnMut(&T) -> bool) -> (Self, Self) {
+        let mut lhs: Self = Self::new();
+        let mut rhs: Self = Self::new();
+        for elem in self {
+            let half: &mut Self = if pred(&elem) { &mut lhs } else { &mut rhs };
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` elements to divide
+            half.data[half.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            half.len = L::from_usize(half.len() + 1);
+        }
+        (lhs, rhs)
+    }
+
+    /// Consumes the StackVec to iterate over its elements in owned chunks.
+    ///
+    /// This is useful for batching the elements into fixed-size frames without allocating. Refuses to compile if `M` is zero.
+    ///
+    /// # Generics
+    /// - `M`: The capacity of the chunks, and thus the number of elements in each of them.
+    ///
+    /// # Returns
+    /// An [`IntoChunks`] iterator yielding StackVecs of `M` elements, in order, of which the last one may be shorter.
+    #[inline]
+    pub fn into_chunks<const M: usize>(self) -> IntoChunks<LEN, M, T, L> {
+        // Chunks must make progress
+        const { const_assert(M > 0, "Cannot split a StackVec into chunks of capacity 0") };
+
+        IntoChunks { iter: self.into_iter() }
+    }
+
+    /// Moves the elements in this StackVec to a heap-allocated [`Vec`].
+    ///
+    /// # Returns
+    /// A new [`Vec`] with the elements of this StackVec, in the same order. Its capacity is exactly the length of this StackVec.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> { Vec::from(self) }
+
+    /// Moves the elements in this StackVec to a heap-allocated [`Box<[T]>`](Box).
+    ///
+    /// # Returns
+    /// A new boxed slice with the elements of this StackVec, in the same order.
+    #[inline]
+    pub fn into_boxed_slice(self) -> Box<[T]> { self.into_vec().into_boxed_slice() }
+
+    /// Moves the elements in this StackVec out into an array, if it is full.
+    ///
+    /// # Returns
+    /// The underlying `[T; LEN]` with all the elements of this StackVec.
+    ///
+    /// # Errors
+    /// If this StackVec is not full, then it is returned unchanged.
+    #[inline]
+    pub fn into_inner(self) -> Result<[T; LEN], Self> {
+        if self.len() < LEN {
+            return Err(self);
+        }
+
+        // Prevent ourselves from dropping the elements we're about to move
+        let this: ManuallyDrop<Self> = ManuallyDrop::new(self);
+        // SAFETY: We asserted that all `LEN` elements are initialized, and `MaybeUninit<T>` has the same layout as `T`. We move them out of `this`, which is never touched again (nor dropped).
+        Ok(unsafe { std::ptr::read(this.data.as_ptr() as *const [T; LEN]) })
+    }
+
+    /// Returns this StackVec as a slice of `T`s.
+    ///
+    /// # Returns
+    /// A [`&[T]`] that has the length of this StackVec. Equivalent to `&stack_vec[..]`.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { &self[..] }
+
+    /// Returns this StackVec as a slice of `T`s.
+    ///
+    /// # Returns
+    /// A [`&mut [T]`] that has the length of this StackVec. Equivalent to `&mut stack_vec[..]`.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [T] { &mut self[..] }
+
+    /// Borrows every element in the StackVec, keeping the references in a StackVec of the same capacity.
+    ///
+    /// This is the equivalent of [`array::each_ref()`](https://doc.rust-lang.org/std/primitive.array.html#method.each_ref). It is useful to, e.g., sort a view of the elements without disturbing their order in this StackVec.
+    ///
+    /// # Returns
+    /// A new StackVec with a reference to each element, in order.
+    #[inline]
+    pub fn each_ref(&self) -> StackVec<LEN, &T, P, L> {
+        let mut res: StackVec<LEN, &T, P, L> = StackVec::new();
+        for (i, elem) in self.iter().enumerate() {
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` elements
+            res.data[i].write(elem);
+        }
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the first `self.len` elements.
+        res.len = self.len;
+        res
+    }
+
+    /// Mutably borrows every element in the StackVec, keeping the references in a StackVec of the same capacity.
+    ///
+    /// This is the equivalent of [`array::each_mut()`](https://doc.rust-lang.org/std/primitive.array.html#method.each_mut).
+    ///
+    /// # Returns
+    /// A new StackVec with a mutable reference to each element, in order.
+    #[inline]
+    pub fn each_mut(&mut self) -> StackVec<LEN, &mut T, P, L> {
+        let len: L = self.len;
+        let mut res: StackVec<LEN, &mut T, P, L> = StackVec::new();
+        for (i, elem) in self.iter_mut().enumerate() {
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` elements
+            res.data[i].write(elem);
+        }
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the first `self.len` elements.
+        res.len = len;
+        res
+    }
+
+    /// Returns a raw pointer to the first element in the StackVec.
+    ///
+    /// The pointer is valid for reads of the first [`Self::len()`](StackVec::len()) elements, for as long as the StackVec is not moved or modified. Note that, since the StackVec lives on the stack, moving it (e.g., returning it from a function) invalidates the pointer.
+    ///
+    /// The memory pointed to must never be written to through this pointer (or any pointer derived from it). Use [`Self::as_mut_ptr()`](StackVec::as_mut_ptr()) for that.
+    ///
+    /// # Returns
+    /// A `*const T` to the start of the StackVec's buffer. This is always non-null and aligned, even if the StackVec is empty.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T { self.data.as_ptr() as *const T }
+
+    /// Returns a mutable raw pointer to the first element in the StackVec.
+    ///
+    /// The pointer is valid for reads of the first [`Self::len()`](StackVec::len()) elements and writes of all [`Self::capacity()`](StackVec::capacity()) elements, for as long as the StackVec is not moved or otherwise accessed. Note that, since the StackVec lives on the stack, moving it (e.g., returning it from a function) invalidates the pointer.
+    ///
+    /// # Returns
+    /// A `*mut T` to the start of the StackVec's buffer. This is always non-null and aligned, even if the StackVec is empty.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.data.as_mut_ptr() as *mut T }
+
+    /// Returns the two raw pointers spanning the initialized elements of the StackVec.
+    ///
+    /// The same caveats as for [`Self::as_ptr()`](StackVec::as_ptr()) apply.
+    ///
+    /// # Returns
+    /// A [`Range`] of `*const T`s, where the start points to the first element and the end points one past the last element.
+    #[inline]
+    pub const fn as_ptr_range(&self) -> Range<*const T> {
+        let start: *const T = self.as_ptr();
+        // SAFETY: `self.len` never exceeds `LEN`, so this is at most one past the end of `self.data`.
+        let end: *const T = unsafe { start.add(self.len()) };
+        start..end
+    }
+
+    /// Returns a reference to an element or subslice of the StackVec, without panicking if it's out-of-bounds.
+    ///
+    /// This is the non-panicking counterpart of indexing, and accepts the same types: a [`usize`] to get a single element, or any of the ranges to get a subslice.
+    ///
+    /// # Arguments
+    /// - `idx`: The index or range of indices to get.
+    ///
+    /// # Returns
+    /// A reference to the element(s), or [`None`] if (part of) `idx` is out-of-bounds.
+    #[inline]
+    pub fn get<I: SliceIndex<[T]>>(&self, idx: I) -> Option<&I::Output> { self.as_slice().get(idx) }
+
+    /// Returns a mutable reference to an element or subslice of the StackVec, without panicking if it's out-of-bounds.
+    ///
+    /// This is the non-panicking counterpart of indexing, and accepts the same types: a [`usize`] to get a single element, or any of the ranges to get a subslice.
+    ///
+    /// # Arguments
+    /// - `idx`: The index or range of indices to get.
+    ///
+    /// # Returns
+    /// A mutable reference to the element(s), or [`None`] if (part of) `idx` is out-of-bounds.
+    #[inline]
+    pub fn get_mut<I: SliceIndex<[T]>>(&mut self, idx: I) -> Option<&mut I::Output> { self.as_slice_mut().get_mut(idx) }
+
+    /// Returns mutable references to multiple elements of the StackVec at once.
+    ///
+    /// # Arguments
+    /// - `indices`: The indices of the elements to get. Must all be in-bounds and pairwise distinct.
+    ///
+    /// # Returns
+    /// An array with a mutable reference to each element, in the same order as `indices`, or [`None`] if any index is out-of-bounds or occurs more than once.
+    #[inline]
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        // Assert the indices are valid
+        for (i, idx) in indices.iter().enumerate() {
+            if *idx >= self.len() || indices[..i].contains(idx) {
+                return None;
+            }
+        }
+
+        // Get the references
+        let ptr: *mut MaybeUninit<T> = self.data.as_mut_ptr();
+        // SAFETY: We asserted that all indices are below `self.len`, so they point to initialized elements (per our `self.len` assertion). Further, they are pairwise distinct, so the mutable references never alias.
+        Some(std::array::from_fn(|i| unsafe { (*ptr.add(indices[i])).assume_init_mut() }))
+    }
+
+    /// Replaces an element in the StackVec with a new one.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to replace.
+    /// - `elem`: The new element to put in its place.
+    ///
+    /// # Returns
+    /// The old element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case (and `elem` is dropped).
+    #[inline]
+    pub fn replace(&mut self, idx: usize, elem: T) -> Option<T> { self.get_mut(idx).map(|old| std::mem::replace(old, elem)) }
+
+    /// Removes an element from the StackVec.
+    ///
+    /// This version preserves the order of non-removed elements. This is at the cost of moving all those other elements one place closer.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to return.
+    ///
+    /// # Returns
+    /// The removed element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Option<T> {
+        if idx < self.len() {
+            // Take the element we're talking about out, leaving its slot logically uninitialized
+            // SAFETY: We assert throughout that all elements before `self.len` are initialized, and `idx` is within range.
+            let res: T = unsafe { self.data[idx].assume_init_read() };
+
+            // Move all the elements after it one to the front with a single (overlapping) copy
+            let ptr: *mut T = self.as_mut_ptr();
+            // SAFETY: Both the source and destination range are within the first `self.len` elements. The copy overwrites the slot we just read from, and leaves the last element duplicated, which we "forget" below.
+            unsafe { std::ptr::copy(ptr.add(idx + 1), ptr.add(idx), self.len() - idx - 1) };
+            // SAFETY: This restores our `self.len` assertion, because the first `self.len - 1` elements are now initialized (and unique).
+            self.len = L::from_usize(self.len() - 1);
+
+            // OK, return that
+            Some(res)
+        } else {
+            // Nothing to be done
+            None
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Removes multiple elements from the StackVec at once.
+    ///
+    /// This version preserves the order of non-removed elements, but unlike repeatedly calling [`Self::remove()`](StackVec::remove()), they are moved only once. The removed elements are dropped.
+    ///
+    /// # Arguments
+    /// - `indices`: The indices of the elements to remove. Must be sorted in strictly ascending order, and refer to the StackVec _before_ any elements are removed.
+    ///
+    /// # Panics
+    /// This function panics if `indices` is not strictly ascending or any of them is out-of-bounds. In that case, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn remove_indices(&mut self, indices: &[usize]) {
+        if !self.try_remove_indices(indices) {
+            panic!("Indices to remove must be sorted in strictly ascending order and within bounds for a StackVec of length {}", self.len());
+        }
+    }
+
+    /// Removes multiple elements from the StackVec at once, if the indices are valid.
+    ///
+    /// This is the non-panicking counterpart of [`Self::remove_indices()`](StackVec::remove_indices()), which describes how the elements are removed.
+    ///
+    /// # Arguments
+    /// - `indices`: The indices of the elements to remove. Must be sorted in strictly ascending order, and refer to the StackVec _before_ any elements are removed.
+    ///
+    /// # Returns
+    /// True if the elements were removed, or false if `indices` is not strictly ascending or any of them is out-of-bounds. The vec is untouched in that case.
+    #[inline]
+    pub fn try_remove_indices(&mut self, indices: &[usize]) -> bool {
+        // Check the indices are valid
+        if indices.windows(2).any(|pair| pair[0] >= pair[1]) || indices.last().is_some_and(|last| *last >= self.len()) {
+            return false;
+        }
+
+        // Pretend we're empty while removing, so a panicking drop at most leaks elements instead of exposing gaps
+        let len: usize = self.len();
+        self.len = L::ZERO;
+
+        // Walk the elements, keeping track of where to write the next kept one
+        let mut indices = indices.iter().peekable();
+        let mut w: usize = 0;
+        for r in 0..len {
+            if indices.next_if_eq(&&r).is_some() {
+                // SAFETY: We use our assertion for `self.len` that the first `len` elements were initialized, and element `r` hasn't been touched yet. We never read it again after this.
+                unsafe { self.data[r].assume_init_drop() };
+            } else {
+                // SAFETY: Element `w` is either `r` itself or a gap left by a dropped element, so swapping moves the kept element to the front.
+                self.data.swap(w, r);
+                w += 1;
+            }
+        }
+
+        // SAFETY: This upholds our `self.len` assertion, because we compacted all kept elements to the first `w` places.
+        self.len = L::from_usize(w);
+        true
+    }
+
+    /// Removes an element from the StackVec, then moves the last element in-place of the removed one.
+    ///
+    /// This version does _not_ preserve the order of non-removed elements. However, this is more efficient, as it does not require us to move all elements in the array but only the last one.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to return.
+    ///
+    /// # Returns
+    /// The removed element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case.
+    #[inline]
+    pub fn swap_remove(&mut self, idx: usize) -> Option<T> {
+        if idx < self.len() {
+            // Swap the selected and the last element
+            // The function itself takes care that this doesn't happen needlessly.
+            // SAFETY: This will not break our `self.len` assertion, because both indices are guaranteed to be below `self.len`, keeping it intact.
+            let last: usize = self.len() - 1;
+            self.data.swap(idx, last);
+
+            // Get the element we're talking about, leaving the old value uninitialized
+            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
+            std::mem::swap(&mut res, &mut self.data[self.len() - 1]);
+            self.len = L::from_usize(self.len() - 1);
+
+            // OK, return that
+            // SAFETY: We assert throughout that all elements before `self.len` are initialized, which was the case above. We also assert that `idx` is within range.
+            Some(unsafe { res.assume_init() })
+        } else {
+            // Nothing to be done
+            None
+        }
+    }
+
+    /// Removes the last element from the StackVec.
+    ///
+    /// # Returns
+    /// An element if there was one, or else [`None`].
+    #[inline]
+    pub const fn pop(&mut self) -> Option<T> {
+        if !self.is_empty() {
+            // Get the element we're talking about, leaving the old value uninitialized
+            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
+            std::mem::swap(&mut res, &mut self.data[self.len() - 1]);
+            self.len = usize_to_len(self.len() - 1);
+
+            // OK, return that
+            // SAFETY: We assert throughout that all elements before `self.len` are initialized, which was the case above. We also asserted there was at least one element.
+            Some(unsafe { res.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last element from the StackVec, but only if it matches the given predicate.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that decides whether the last element should be popped.
+    ///
+    /// # Returns
+    /// The last element if there was one and `pred` returned true for it, or else [`None`].
+    #[inline]
+    pub fn pop_if(&mut self, pred: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        if self.last_mut().is_some_and(pred) { self.pop() } else { None }
+    }
+
+    /// Removes consecutive elements from the StackVec that are considered the same by the given relation.
+    ///
+    /// The `same_bucket` closure is given every element (as first argument) together with the last element that was kept before it (as second argument). If it returns true, the element is removed. As such, of every run of elements in the same bucket, only the first is kept. The remaining elements are moved to the front, preserving their order.
+    ///
+    /// # Arguments
+    /// - `same_bucket`: A closure that decides whether two elements are considered duplicates.
+    #[inline]
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        if self.len() <= 1 {
+            return;
+        }
+
+        /// Closes the gap between the kept and the unvisited elements when dropped, so the StackVec stays valid even if `same_bucket` panics.
+        struct FillGapOnDrop<'v, const LEN: usize, T, P, L: LenType> {
+            /// The StackVec we're deduplicating.
+            vec:   &'v mut StackVec<LEN, T, P, L>,
+            /// The index of the next element to visit.
+            read:  usize,
+            /// The index to write the next kept element to.
+            write: usize,
+            /// The length of the StackVec before deduplicating.
+            len:   usize,
+        }
+        impl<'v, const LEN: usize, T, P, L: LenType> Drop for FillGapOnDrop<'v, LEN, T, P, L> {
+            #[inline]
+            fn drop(&mut self) {
+                let ptr: *mut T = self.vec.data.as_mut_ptr() as *mut T;
+                // SAFETY: The elements from `read` up to `len` are unvisited and thus still initialized, and moving them to `write <= read` keeps them within bounds.
+                unsafe { std::ptr::copy(ptr.add(self.read), ptr.add(self.write), self.len - self.read) };
+                // SAFETY: This upholds our `self.len` assertion, because the first `write` elements are the kept ones, directly followed by the unvisited ones.
+                self.vec.len = L::from_usize(self.write + (self.len - self.read));
+            }
+        }
+
+        // Walk the elements, keeping track of where to write the next unique one
+        let len: usize = self.len();
+        let mut gap: FillGapOnDrop<LEN, T, P, L> = FillGapOnDrop { vec: self, read: 1, write: 1, len };
+        // NOTE: All accesses in the loop go through this one pointer, so they don't invalidate each other
+        let ptr: *mut T = gap.vec.data.as_mut_ptr() as *mut T;
+        while gap.read < len {
+            let (r, w): (usize, usize) = (gap.read, gap.write);
+            // SAFETY: We use our assertion for `self.len` that the first `len` elements were initialized. Element `r` hasn't been visited yet, and `w - 1 < r` is the last kept element, so the two references never alias.
+            let dup: bool = unsafe { same_bucket(&mut *ptr.add(r), &mut *ptr.add(w - 1)) };
+            if dup {
+                // Advance first, so the guard doesn't touch the element again if dropping it panics
+                gap.read += 1;
+                // SAFETY: See above. We never read element `r` again after this.
+                unsafe { std::ptr::drop_in_place(ptr.add(r)) };
+            } else {
+                // SAFETY: Element `w` is either `r` itself or a gap left by a dropped or moved element, so this moves the kept element to the front. `ptr::copy` allows `w == r`.
+                unsafe { std::ptr::copy(ptr.add(r), ptr.add(w), 1) };
+                gap.read += 1;
+                gap.write += 1;
+            }
+        }
+        // NOTE: Dropping the guard now closes an empty gap, setting the length to the number of kept elements
+    }
+
+    /// Removes consecutive elements from the StackVec that resolve to the same key.
+    ///
+    /// Of every run of elements with equal keys, only the first is kept. The remaining elements are moved to the front, preserving their order.
+    ///
+    /// # Arguments
+    /// - `key`: A closure that computes the key to compare for an element.
+    #[inline]
+    pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) { self.dedup_by(|lhs, rhs| key(lhs) == key(rhs)) }
+
+    /// Removes the first element from the StackVec.
+    ///
+    /// Note that this moves all other elements one place closer, making it O(n). Equivalent to calling [`Self::remove(0)`](StackVec::remove()).
+    ///
+    /// # Returns
+    /// An element if there was one, or else [`None`].
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> { self.remove(0) }
+
+    /// Removes _all_ elements from the StackVec, starting afresh.
+    ///
+    /// This cannot be `const`, as it may have to drop the elements. For [`Copy`] elements, use [`Self::clear_copy()`](StackVec::clear_copy()) in `const` contexts instead.
+    #[inline]
+    pub fn clear(&mut self) {
+        // Drop all elements in ourselves (if they need it)
+        if std::mem::needs_drop::<T>() {
+            for i in 0..self.len() {
+                // SAFETY: OK because `i` is guaranteed to be below `self.len`, and we asserted the first `self.len` elements are ininitialized.
+                unsafe {
+                    self.data[i].assume_init_drop();
+                }
+            }
+        }
+
+        // Reset the length to reset the elements
+        self.len = L::ZERO;
+    }
+
+    /// Removes all elements matching the given predicate from the StackVec, yielding them as an iterator.
+    ///
+    /// The elements that are kept preserve their relative order. Elements are only examined (and removed) as the iterator is advanced; if it is dropped early, any unvisited elements are kept.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that returns true for every element that should be extracted.
+    ///
+    /// # Returns
+    /// An [`ExtractIf`] iterator that yields the extracted elements in order.
+    #[inline]
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, LEN, T, F, P, L> {
+        // Pretend we're empty while extracting, so a leaked iterator at most leaks elements instead of exposing gaps
+        let len: usize = self.len();
+        self.len = L::ZERO;
+        ExtractIf { vec: self, pred, i: 0, del: 0, len }
+    }
+
+    /// Shortens the StackVec to the given length, dropping any elements beyond it.
+    ///
+    /// If `len` is greater than or equal to the current length, this does nothing.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the StackVec.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            // Drop all elements beyond the new length (if they need it)
+            if std::mem::needs_drop::<T>() {
+                for i in len..self.len() {
+                    // SAFETY: OK because `i` is guaranteed to be below `self.len`, and we asserted the first `self.len` elements are ininitialized.
+                    unsafe { self.data[i].assume_init_drop() };
+                }
+            }
+
+            // SAFETY: This upholds our `self.len` assertion, because the first `len` elements are still initialized.
+            self.len = L::from_usize(len);
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Resizes the StackVec in-place to the given length, calling a closure to generate any new elements.
+    ///
+    /// If `new_len` is smaller than the current length, the StackVec is simply truncated (see [`Self::truncate()`](StackVec::truncate())).
+    ///
+    /// # Arguments
+    /// - `new_len`: The new length of the StackVec.
+    /// - `f`: A closure that is called once for every new element to generate it.
+    ///
+    /// # Panics
+    /// This function panics if `new_len` exceeds the capacity of the StackVec. In that case, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn resize_with(&mut self, new_len: usize, f: impl FnMut() -> T) {
+        if self.try_resize_with(new_len, f).is_err() {
+            panic!("Cannot resize StackVec of capacity {} to length {}", LEN, new_len);
+        }
+    }
+
+    /// Resizes the StackVec in-place to the given length, calling a closure to generate any new elements, returning an error if it does not fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::resize_with()`](StackVec::resize_with()).
+    ///
+    /// # Arguments
+    /// - `new_len`: The new length of the StackVec.
+    /// - `f`: A closure that is called once for every new element to generate it.
+    ///
+    /// # Errors
+    /// If `new_len` exceeds the capacity of the StackVec, a [`CapacityError`] is returned that carries the number of elements that would not have fit. The vec is untouched in that case.
+    #[inline]
+    pub fn try_resize_with(&mut self, new_len: usize, mut f: impl FnMut() -> T) -> Result<(), CapacityError> {
+        if new_len > LEN {
+            return Err(CapacityError::with_excess((), LEN, new_len - LEN));
+        }
+        if new_len <= self.len() {
+            self.truncate(new_len);
+        } else {
+            while self.len() < new_len {
+                self.data[self.len()].write(f());
+                // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+                self.len = L::from_usize(self.len() + 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Overwrites all current elements in the StackVec with values generated by a closure.
+    ///
+    /// Only the initialized elements are touched; the length of the StackVec does not change.
+    ///
+    /// # Arguments
+    /// - `f`: A closure that is called once for every element to generate its new value.
+    #[inline]
+    pub fn fill_with(&mut self, f: impl FnMut() -> T) { self.as_slice_mut().fill_with(f) }
 
+    #[cfg(not(feature = "no-panic"))]
+    /// Swaps two elements in the StackVec.
+    ///
+    /// # Arguments
+    /// - `a`: The index of the first element.
+    /// - `b`: The index of the second element.
+    ///
+    /// # Panics
+    /// This function panics if either `a` or `b` is out-of-bounds.
+    #[inline]
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.len() {
+            panic!("Index {} is out-of-bounds for a StackVec of length {}", a, self.len());
+        }
+        if b >= self.len() {
+            panic!("Index {} is out-of-bounds for a StackVec of length {}", b, self.len());
+        }
+        // SAFETY: We just asserted both indices are within bounds.
+        unsafe { self.swap_unchecked(a, b) }
+    }
 
-/***** TESTS *****/
-#[cfg(test)]
-pub mod tests {
-    use super::StackVec;
+    #[cfg(feature = "no-panic")]
+    /// Swaps two elements in the StackVec, doing nothing if either is out-of-bounds.
+    ///
+    /// This is the version of this function under the `no-panic` feature. It shadows [`<[T]>::swap()`](slice::swap()), which would otherwise still be reachable through [`Deref`] and panic. Use [`Self::try_swap()`](StackVec::try_swap()) to find out whether the elements were swapped.
+    ///
+    /// # Arguments
+    /// - `a`: The index of the first element.
+    /// - `b`: The index of the second element.
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) { self.try_swap(a, b); }
 
-    #[test]
-    fn extend() {
-        // Build a vec
-        let vec: StackVec<3, String> = StackVec::from(["D".into(), "E".into(), "F".into()]);
-        // Build another vec that extends
-        let mut vec2: StackVec<6, String> = StackVec::from(["A".into(), "B".into(), "C".into()]);
-        vec2.extend(vec);
-        assert_eq!(vec2, ["A".into(), "B".into(), "C".into(), "D".into(), "E".into(), "F".into()]);
+    /// Swaps two elements in the StackVec, if both are within bounds.
+    ///
+    /// # Arguments
+    /// - `a`: The index of the first element.
+    /// - `b`: The index of the second element.
+    ///
+    /// # Returns
+    /// True if the elements were swapped, or false if either `a` or `b` is out-of-bounds. The vec is untouched in that case.
+    #[inline]
+    pub fn try_swap(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.len() || b >= self.len() {
+            return false;
+        }
+        // SAFETY: We just asserted both indices are within bounds.
+        unsafe { self.swap_unchecked(a, b) };
+        true
     }
 
-    #[test]
-    fn extend_empty() {
-        // Extend an empty vec
-        let mut vec3: StackVec<3, String> = StackVec::from(["A".into(), "B".into(), "C".into()]);
-        vec3.extend(StackVec::<3, String>::new());
-        assert_eq!(vec3, ["A".into(), "B".into(), "C".into()]);
+    /// Swaps two elements in the StackVec without checking bounds.
+    ///
+    /// # Arguments
+    /// - `a`: The index of the first element.
+    /// - `b`: The index of the second element.
+    ///
+    /// # Safety
+    /// The caller must ensure that both `a` and `b` are below the length of the StackVec. Otherwise, this function will swap an uninitialized element into the initialized part (or worse, write out-of-bounds).
+    #[inline]
+    pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+        let ptr: *mut MaybeUninit<T> = self.data.as_mut_ptr();
+        // SAFETY: The caller promises both indices are within `self.len`, which is within `LEN`. `ptr::swap` allows `a == b`.
+        unsafe { std::ptr::swap(ptr.add(a), ptr.add(b)) }
     }
 
-    #[test]
-    fn extend_vec() {
-        // Extend a vec of 1 into a vector
-        let mut vec4: Vec<String> = Vec::with_capacity(5);
-        vec4.extend(StackVec::<5, String>::from(["Hello there!".into(), "General Kenobi!".into()]));
-        assert_eq!(vec4, vec!["Hello there!".to_string(), "General Kenobi!".to_string()]);
+    /// Pushes a new element to the end of the StackVec, panicking if it is full.
+    ///
+    /// This is the behaviour of [`Self::push()`](StackVec::push()) under the [`PanicOnFull`] policy, but available regardless of policy.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn push_or_panic(&mut self, elem: T) {
+        // Assert there is enough space
+        if self.len() < LEN {
+            self.data[self.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = L::from_usize(self.len() + 1);
+        } else {
+            panic!("Cannot push {}th element to StackVec of capacity {}", self.len() + 1, LEN);
+        }
     }
 
-    #[test]
-    fn extend_vec_empty() {
-        // Extend an empty vec into a vector
-        let mut vec4: Vec<String> = Vec::with_capacity(5);
-        vec4.extend(StackVec::<5, String>::new());
-        assert_eq!(vec4, Vec::<String>::new());
+    /// Pushes a new element to the end of the StackVec if there is enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push()`](StackVec::push()), mirroring [`Vec::push_within_capacity()`]. See [`Self::try_push()`](StackVec::try_push()) for a version returning a [`CapacityError`].
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, the given `elem` is returned in the error instead. The vec is untouched in that case.
+    #[inline]
+    pub const fn push_within_capacity(&mut self, elem: T) -> Result<(), T> {
+        if self.len() < LEN {
+            self.data[self.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = usize_to_len(self.len() + 1);
+            Ok(())
+        } else {
+            Err(elem)
+        }
     }
 
-    #[test]
-    fn sort() {
-        // Do one with a from
-        let mut vec: StackVec<5, &'static str> = StackVec::from(["Dan", "Amy", "Cho", "Eve", "Bob"]);
-        vec.sort();
-        assert_eq!(vec, ["Amy", "Bob", "Cho", "Dan", "Eve"]);
+    /// Pushes a new element to the end of the StackVec, evicting the first element if it is full.
+    ///
+    /// This is useful to keep the `LEN` most recent elements. Note that evicting moves all other elements one place closer, making it O(n).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Returns
+    /// The evicted element if the StackVec was full, or else [`None`]. If the capacity is 0, `elem` itself is returned.
+    #[inline]
+    pub fn push_rotate(&mut self, elem: T) -> Option<T> {
+        if LEN == 0 {
+            return Some(elem);
+        }
+        let evicted: Option<T> = if self.is_full() { self.pop_front() } else { None };
+        self.push_or_panic(elem);
+        evicted
     }
-}
 
+    #[cfg(not(feature = "no-panic"))]
+    /// Pushes a new element to the front of the StackVec.
+    ///
+    /// Note that this moves all other elements one place back, making it O(n). Equivalent to calling [`Self::insert(0, elem)`](StackVec::insert()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Panics
+    /// This function can panic if the there isn't enough space in the Vec. You can prevent this by manually checking for space, i.e.,
+    /// ```ignore
+    /// if !stack_vec.is_full() {
+    ///     // Never panics now
+    ///     stack_vec.push_front(elem);
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn push_front(&mut self, elem: T) { self.insert(0, elem) }
 
+    /// Pushes a new element to the front of the StackVec, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push_front()`](StackVec::push_front()). Like it, this is O(n).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn try_push_front(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        // NOTE: Index 0 is always in-bounds, so the only thing that can go wrong is capacity
+        self.try_insert(0, elem).map_err(|err| CapacityError::new(err.into_element(), LEN))
+    }
 
+    /// Pushes a new element to the end of the StackVec, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push()`](StackVec::push()). Unlike it, this function is `const`, so it can be used to build StackVecs in `const` items and `static` initializers.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub const fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        // NOTE: Not implemented via `Self::push_within_capacity()`, as `const` can't tell that its result never needs dropping
+        if self.len() < LEN {
+            self.data[self.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = usize_to_len(self.len() + 1);
+            Ok(())
+        } else {
+            Err(CapacityError::new(elem, LEN))
+        }
+    }
 
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element in the StackVec at a given location.
+    ///
+    /// The insert location must either replace an existing element, or be exactly after the last element. Anything else is considered out-of-bounds.
+    ///
+    /// The replaced element and all elements after it are pushed one space back to preserve array order.
+    ///
+    /// # Arguments
+    /// - `idx`: The index to insert the new element in.
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Panic
+    /// This function panics if the given `idx` is out-of-bounds by more than 1 (i.e., one place outside of the current length is OK, emulating a [`Self::push()`](StackVec::push())).
+    ///
+    /// Another panic case is if there is not enough capacity to store the extra element. You can prevent this by manually checking for space, i.e.,
+    /// ```ignore
+    /// if !stack_vec.is_full() {
+    ///     // Never panics now
+    ///     stack_vec.push(elem);
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, idx: usize, elem: T) {
+        match self.try_insert(idx, elem) {
+            Ok(()) => {},
+            Err(InsertError::OutOfBounds { .. }) => panic!("Inserting at index {} is out-of-bounds for StackVec of length {}", idx, self.len()),
+            Err(InsertError::Capacity(_)) => panic!("Cannot push {}th element to StackVec of capacity {}", self.len() + 1, LEN),
+        }
+    }
 
-/***** HELPER MACROS *****/
-/// Implements [`Index`] and [`IndexMut`] for a particular range.
-macro_rules! index_range_impl {
-    ($range:ty, $conv:expr) => {
-        impl<const LEN: usize, T> Index<$range> for StackVec<LEN, T> {
-            type Output = [T];
+    /// Inserts a new element in the StackVec at a given location, returning an error if that's not possible.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](StackVec::insert()). The same rules apply to the location.
+    ///
+    /// # Arguments
+    /// - `idx`: The index to insert the new element in.
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Errors
+    /// This function errors if there is not enough capacity to store the extra element, or if the given `idx` is out-of-bounds by more than 1. In both cases, the returned [`InsertError`] carries the given `elem` and the vec is untouched.
+    #[inline]
+    pub fn try_insert(&mut self, idx: usize, elem: T) -> Result<(), InsertError<T>> {
+        // Assert there is enough space
+        if self.len() >= LEN {
+            return Err(InsertError::Capacity(CapacityError::new(elem, LEN)));
+        }
+        // Assert the index is within bounds
+        if idx > self.len() {
+            return Err(InsertError::OutOfBounds { idx, len: self.len(), elem });
+        }
 
-            #[inline]
-            #[track_caller]
-            fn index(&self, index: $range) -> &Self::Output {
-                // Get a proper range out of this
-                let (start, end): (usize, usize) = $conv(self.len, index);
+        // Move all elements one further with a single (overlapping) copy
+        let ptr: *mut T = self.as_mut_ptr();
+        // SAFETY: The source range is within the first `self.len` elements, and the destination range ends at `self.len + 1`, which is within `LEN` as we asserted above.
+        //         This temporarily BREAKS our `self.len` assertion, because the element at `idx` is now duplicated. This will, however, be remedied below.
+        unsafe { std::ptr::copy(ptr.add(idx), ptr.add(idx + 1), self.len() - idx) };
 
-                // Create a slice
-                // SAFETY: Gotta prove two things here;
-                // - We can safely assume that the [`MaybeUninit`]s are initialized, because of our assertion for `self.len` that the first `self.len` elements are always initialized, and we made sure that the `index` is within that space; and
-                // - We can call `std::mem::transmute` because `T` and `MaybeUninit<T>` are guaranteed to have the same layout. Conditions on stuff like abusing niche values do not apply, since we don't inherently enum the MaybeUninit, and neither does slice do anything with it.
-                unsafe { std::mem::transmute(&self.data[start..end]) }
-            }
+        // Now insert the element
+        // SAFETY: This restores our `self.len` assertion, because we overwrite the duplicated element (without dropping it, as it now lives at `idx + 1`).
+        self.data[idx].write(elem);
+        // SAFETY: This is OK, because we moved the then-last element into the space at the end.
+        self.len = L::from_usize(self.len() + 1);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts multiple new elements in the StackVec at a given location.
+    ///
+    /// Any elements at or after `idx` are moved back to make room for the new elements, preserving array order. Unlike repeatedly calling [`Self::insert()`](StackVec::insert()), they are only moved once.
+    ///
+    /// # Arguments
+    /// - `idx`: The index to insert the first new element in.
+    /// - `elems`: Something [iterable](IntoIterator) with a known length that generates the new elements, in order.
+    ///
+    /// # Panics
+    /// This function panics if the given `idx` is out-of-bounds by more than 1 (i.e., one place outside of the current length is OK, emulating a [`Self::extend()`](StackVec::extend())).
+    ///
+    /// Another panic case is if there is not enough capacity to store all the new elements. In both cases, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn insert_many<I>(&mut self, idx: usize, elems: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        match self.try_insert_many(idx, elems) {
+            Ok(()) => {},
+            Err(InsertError::OutOfBounds { .. }) => panic!("Inserting at index {} is out-of-bounds for StackVec of length {}", idx, self.len()),
+            Err(InsertError::Capacity(err)) => {
+                panic!("Cannot insert {} elements in StackVec of length {} and capacity {}", err.element().len(), self.len(), LEN)
+            },
         }
-        impl<const LEN: usize, T> IndexMut<$range> for StackVec<LEN, T> {
-            #[inline]
-            #[track_caller]
-            fn index_mut(&mut self, index: $range) -> &mut Self::Output {
-                // Get a proper range out of this
-                let (start, end): (usize, usize) = $conv(self.len, index);
+    }
 
-                // Create a slice
-                // SAFETY: Gotta prove two things here;
-                // - We can safely assume that the [`MaybeUninit`]s are initialized, because of our assertion for `self.len` that the first `self.len` elements are always initialized, and we made sure that the `index` is within that space; and
-                // - We can call `std::mem::transmute` because `T` and `MaybeUninit<T>` are guaranteed to have the same layout. Conditions on stuff like abusing niche values do not apply, since we don't inherently enum the MaybeUninit, and neither does slice do anything with it.
-                unsafe { std::mem::transmute(&mut self.data[start..end]) }
+    /// Inserts multiple new elements in the StackVec at a given location, returning an error if that's not possible.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert_many()`](StackVec::insert_many()). The same rules apply to the location.
+    ///
+    /// # Arguments
+    /// - `idx`: The index to insert the first new element in.
+    /// - `elems`: Something [iterable](IntoIterator) with a known length that generates the new elements, in order.
+    ///
+    /// # Errors
+    /// An [`InsertError`] is returned that carries the (unconsumed) iterator if `idx` is out-of-bounds by more than 1 or there is not enough capacity to store all the new elements. The vec is untouched in that case.
+    #[inline]
+    pub fn try_insert_many<I>(&mut self, idx: usize, elems: I) -> Result<(), InsertError<I::IntoIter>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let elems: I::IntoIter = elems.into_iter();
+        let n: usize = elems.len();
+        if idx > self.len() {
+            return Err(InsertError::OutOfBounds { idx, len: self.len(), elem: elems });
+        }
+        if self.len() + n > LEN {
+            return Err(InsertError::Capacity(CapacityError::with_excess(elems, LEN, self.len() + n - LEN)));
+        }
+
+        /// Moves the tail back against the inserted elements when dropped, so the StackVec stays valid even if the iterator panics or yields too few elements.
+        struct RestoreTailOnDrop<'v, const LEN: usize, T, P, L: LenType> {
+            /// The StackVec we're inserting in.
+            vec:  &'v mut StackVec<LEN, T, P, L>,
+            /// The index right after the last inserted element.
+            end:  usize,
+            /// The index where the tail currently starts.
+            from: usize,
+            /// The number of elements in the tail.
+            tail: usize,
+        }
+        impl<'v, const LEN: usize, T, P, L: LenType> Drop for RestoreTailOnDrop<'v, LEN, T, P, L> {
+            #[inline]
+            fn drop(&mut self) {
+                let ptr: *mut T = self.vec.data.as_mut_ptr() as *mut T;
+                // SAFETY: Both ranges are within `self.data`, as `end <= from` and the tail was moved there, and `ptr::copy` allows them to overlap.
+                unsafe { std::ptr::copy(ptr.add(self.from), ptr.add(self.end), self.tail) };
+                // SAFETY: This upholds our `self.len` assertion, because the first `end` elements are initialized, directly followed by the tail.
+                self.vec.len = L::from_usize(self.end + self.tail);
             }
         }
-    };
-}
 
+        // Move the tail back to make room
+        let tail: usize = self.len() - idx;
+        let mut guard: RestoreTailOnDrop<LEN, T, P, L> = RestoreTailOnDrop { vec: self, end: idx, from: idx + n, tail };
+        // NOTE: All accesses go through this one pointer, so they don't invalidate each other
+        let ptr: *mut T = guard.vec.data.as_mut_ptr() as *mut T;
+        // SAFETY: We asserted that `idx + n + tail` (i.e., the old length plus `n`) fits within `LEN`, so both ranges are within `self.data`. `ptr::copy` allows them to overlap.
+        unsafe { std::ptr::copy(ptr.add(idx), ptr.add(idx + n), tail) };
 
+        // Write the new elements in the gap
+        for elem in elems.take(n) {
+            // SAFETY: `guard.end` is below `idx + n`, which is within `self.data` (see above), and part of the gap.
+            unsafe { ptr.add(guard.end).write(elem) };
+            guard.end += 1;
+        }
+        // NOTE: Dropping the guard now restores the tail, also closing the gap if the iterator yielded fewer elements than it promised
+        Ok(())
+    }
 
+    /// Inserts a new element in a sorted StackVec such that it stays sorted, using a comparator function.
+    ///
+    /// The insertion point is found using a binary search. If there are elements equal to `elem`, it is inserted after them.
+    ///
+    /// Note that the StackVec is assumed to be sorted w.r.t. the given comparator. If it isn't, the insertion point is unspecified (but it is still inserted).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    /// - `compare`: A comparator function that orders two elements.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn insert_sorted_by(&mut self, elem: T, mut compare: impl FnMut(&T, &T) -> Ordering) -> Result<usize, CapacityError<T>> {
+        let idx: usize = self.partition_point(|other| compare(other, &elem) != Ordering::Greater);
+        // NOTE: The index is always in-bounds, so the only thing that can go wrong is capacity
+        self.try_insert(idx, elem).map_err(|err| CapacityError::new(err.into_element(), LEN))?;
+        Ok(idx)
+    }
 
+    /// Inserts a new element in a sorted StackVec such that it stays sorted, using a key extraction function.
+    ///
+    /// The insertion point is found using a binary search. If there are elements with a key equal to that of `elem`, it is inserted after them.
+    ///
+    /// Note that the StackVec is assumed to be sorted by the given key. If it isn't, the insertion point is unspecified (but it is still inserted).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    /// - `key`: A function that extracts the key to sort on from an element.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn insert_sorted_by_key<K: Ord>(&mut self, elem: T, mut key: impl FnMut(&T) -> K) -> Result<usize, CapacityError<T>> {
+        self.insert_sorted_by(elem, |lhs, rhs| key(lhs).cmp(&key(rhs)))
+    }
 
-/***** ITERATORS *****/
-/// Iterates over a [`StackVec`] by ownership.
-#[derive(Clone, Debug)]
-pub struct IntoIter<const LEN: usize, T> {
-    /// Some [`StackVec`] that we iterate over.
-    vec: StackVec<LEN, T>,
-    /// The current index of iteration.
-    i:   usize,
-    /// The current end of the iteration. Exclusive (so `0` means nothing).
-    end: usize,
-}
+    /// Extends this StackVec with any number of new elements, panicking if they do not all fit.
+    ///
+    /// This is the behaviour of [`Self::extend()`](StackVec::extend()) under the [`PanicOnFull`] policy, but available regardless of policy.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn extend_or_panic(&mut self, elems: impl IntoIterator<Item = T>) {
+        // No need to reserve, we have all the capacity we ever get; but we can fail before writing anything if the iterator promises too many elements
+        let mut elems = elems.into_iter();
+        let (lower, upper): (usize, Option<usize>) = elems.size_hint();
+        if lower > LEN - self.len() {
+            panic!("Cannot extend StackVec of length {} and capacity {} with at least {} elements", self.len(), LEN, lower);
+        }
 
-impl<const LEN: usize, T> Default for IntoIter<LEN, T> {
-    /// Creates an empty iterator.
+        // If the exact length is known, write those elements without checking the capacity for each of them
+        if upper == Some(lower) {
+            let mut n: usize = 0;
+            let len: usize = self.len();
+            for (slot, elem) in self.data[len..len + lower].iter_mut().zip(elems.by_ref()) {
+                slot.write(elem);
+                n += 1;
+            }
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the `n` elements after the first `self.len`.
+            self.len = L::from_usize(self.len() + n);
+        }
+
+        // Push any (remaining) elements one-by-one, in case the iterator lied about its length
+        for elem in elems {
+            self.push_or_panic(elem);
+        }
+    }
+
+    /// Extends this StackVec with any number of new elements, returning an error if they do not all fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::extend()`](StackVec::extend()). Unlike that function, the capacity is checked up-front using the iterator's exact length, so either all elements are appended or none are.
+    ///
+    /// # Arguments
+    /// - `elems`: Something [iterable](IntoIterator) with a known length that generates the elements to append.
+    ///
+    /// # Errors
+    /// If the elements do not all fit, a [`CapacityError`] is returned that carries the untouched iterator and the number of elements that would not have fit. The vec is untouched in that case.
     #[inline]
-    fn default() -> Self { Self { vec: StackVec::default(), i: 0, end: 0 } }
-}
-impl<const LEN: usize, T> Drop for IntoIter<LEN, T> {
+    pub fn try_extend<I>(&mut self, elems: I) -> Result<(), CapacityError<I::IntoIter>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        // Assert there is enough space
+        let elems: I::IntoIter = elems.into_iter();
+        let n: usize = elems.len();
+        if self.len() + n > LEN {
+            return Err(CapacityError::with_excess(elems, LEN, self.len() + n - LEN));
+        }
+
+        // Write the elements, without trusting the iterator's length blindly
+        for elem in elems.take(n) {
+            self.data[self.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = L::from_usize(self.len() + 1);
+        }
+        Ok(())
+    }
+
+    /// Extends this StackVec with new elements until it is full, handing back any remaining ones.
+    ///
+    /// The iterator is never advanced beyond the element that fills the StackVec, so no elements are lost.
+    ///
+    /// # Arguments
+    /// - `elems`: Something [iterable](IntoIterator) that generates the elements to append.
+    ///
+    /// # Returns
+    /// The iterator with any elements that did not fit.
     #[inline]
-    fn drop(&mut self) {
-        // Drop any remaining elements
-        while self.i < self.end {
-            // SAFETY: This is OK because of the `self.len` assertion and `i` is below that length (because `end` is below that length).
-            unsafe { self.vec.data[self.i].assume_init_drop() };
-            self.i += 1;
+    pub fn extend_until_full<I: IntoIterator<Item = T>>(&mut self, elems: I) -> I::IntoIter {
+        let mut elems: I::IntoIter = elems.into_iter();
+        while self.len() < LEN {
+            match elems.next() {
+                Some(elem) => {
+                    self.data[self.len()].write(elem);
+                    // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+                    self.len = L::from_usize(self.len() + 1);
+                },
+                None => break,
+            }
+        }
+        elems
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Moves all elements of another StackVec to the end of this one, leaving the other empty.
+    ///
+    /// The elements are appended in the same order as they appear in `other`.
+    ///
+    /// # Arguments
+    /// - `other`: The StackVec to move the elements out of. May have a different capacity than this one.
+    ///
+    /// # Panics
+    /// This function panics if the combined number of elements exceeds the capacity of this StackVec. In that case, neither vec is touched.
+    #[inline]
+    #[track_caller]
+    pub fn append<const LEN2: usize, P2, L2: LenType>(&mut self, other: &mut StackVec<LEN2, T, P2, L2>) {
+        if self.try_append(other).is_err() {
+            panic!("Cannot append {} elements to StackVec of length {} and capacity {}", other.len(), self.len(), LEN);
+        }
+    }
+
+    /// Moves all elements of another StackVec to the end of this one, leaving the other empty, or returns an error if they do not all fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::append()`](StackVec::append()).
+    ///
+    /// # Arguments
+    /// - `other`: The StackVec to move the elements out of. May have a different capacity than this one.
+    ///
+    /// # Errors
+    /// If the combined number of elements exceeds the capacity of this StackVec, a [`CapacityError`] is returned that carries the number of elements that would not have fit. Neither vec is touched in that case.
+    #[inline]
+    pub fn try_append<const LEN2: usize, P2, L2: LenType>(&mut self, other: &mut StackVec<LEN2, T, P2, L2>) -> Result<(), CapacityError> {
+        // Check there is enough space
+        if self.len() + other.len() > LEN {
+            return Err(CapacityError::with_excess((), LEN, self.len() + other.len() - LEN));
         }
 
-        // Now manually edit the length of the stackvec to prevent it dropping anything
-        self.vec.len = 0;
-    }
-}
+        // Move the elements over
+        for i in 0..other.len() {
+            // SAFETY: We use our assertion for `other.len` that the first `other.len` elements are initialized. We reset `other.len` below so they are never read again.
+            self.data[self.len() + i].write(unsafe { other.data[i].assume_init_read() });
+        }
 
-impl<const LEN: usize, T> Iterator for IntoIter<LEN, T> {
-    type Item = T;
+        // Update the lengths
+        // SAFETY: This upholds our `self.len` assertion for both vecs, because we initialized the elements we promise are initialized in `self` and moved all out of `other`.
+        self.len = L::from_usize(self.len() + other.len());
+        other.len = L2::ZERO;
+        Ok(())
+    }
 
+    /// Returns a reference to the first element in the StackVec.
+    ///
+    /// # Returns
+    /// A reference to the first element, or [`None`] if the StackVec is empty.
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.i < self.end {
-            // Get the element
-            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
-            std::mem::swap(&mut res, &mut self.vec.data[self.i]);
-            self.i += 1;
-
-            // SAFETY: This is OK because of the `self.len` assertion and `i` is below that length (because `end` is below that length).
-            Some(unsafe { res.assume_init() })
+    pub const fn first(&self) -> Option<&T> {
+        if !self.is_empty() {
+            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and we asserted there is at least one.
+            Some(unsafe { self.data[0].assume_init_ref() })
         } else {
             None
         }
     }
 
+    /// Returns a mutable reference to the first element in the StackVec.
+    ///
+    /// # Returns
+    /// A mutable reference to the first element, or [`None`] if the StackVec is empty.
     #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) { (self.end - self.i, Some(self.end - self.i)) }
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        if !self.is_empty() {
+            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and we asserted there is at least one.
+            Some(unsafe { self.data[0].assume_init_mut() })
+        } else {
+            None
+        }
+    }
 
+    /// Returns a reference to the last element in the StackVec.
+    ///
+    /// # Returns
+    /// A reference to the last element, or [`None`] if the StackVec is empty.
     #[inline]
-    fn count(self) -> usize
-    where
-        Self: Sized,
-    {
-        self.end - self.i
+    pub const fn last(&self) -> Option<&T> {
+        if !self.is_empty() {
+            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and we asserted there is at least one.
+            Some(unsafe { self.data[self.len() - 1].assume_init_ref() })
+        } else {
+            None
+        }
     }
-}
-impl<const LEN: usize, T> DoubleEndedIterator for IntoIter<LEN, T> {
-    #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.end > 0 {
-            // Get the element
-            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
-            std::mem::swap(&mut res, &mut self.vec.data[self.end]);
-            self.end -= 1;
 
-            // SAFETY: This is OK because of the `self.len` assertion and `end` is below that length (given at construction).
-            Some(unsafe { res.assume_init() })
+    /// Returns a mutable reference to the last element in the StackVec.
+    ///
+    /// # Returns
+    /// A mutable reference to the last element, or [`None`] if the StackVec is empty.
+    #[inline]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        if !self.is_empty() {
+            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and we asserted there is at least one.
+            Some(unsafe { self.data[self.len() - 1].assume_init_mut() })
         } else {
             None
         }
     }
-}
-impl<const LEN: usize, T> ExactSizeIterator for IntoIter<LEN, T> {
+
+    /// Returns the number of elements stored in the StackVec.
+    ///
+    /// Unlike the one on slices, this version can be used in `const` contexts.
     #[inline]
-    fn len(&self) -> usize { self.end - self.i }
-}
-impl<const LEN: usize, T> FusedIterator for IntoIter<LEN, T> {}
+    pub const fn len(&self) -> usize { len_to_usize(&self.len) }
+
+    /// Returns whether any elements are stored in the StackVec at all.
+    ///
+    /// Unlike the one on slices, this version can be used in `const` contexts.
+    ///
+    /// # Returns
+    /// True if there are 0 elements, false if there is at least 1.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
 
+    /// Returns the remaining, uninitialized capacity of the StackVec.
+    ///
+    /// This can be used to initialize elements in-place, after which [`Self::set_len()`](StackVec::set_len()) can be used to mark them as initialized.
+    ///
+    /// # Returns
+    /// A slice of [`MaybeUninit<T>`]s with `self.capacity() - self.len()` elements.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let len: usize = self.len();
+        &mut self.data[len..]
+    }
+
+    /// Returns both the initialized elements and the remaining, uninitialized capacity of the StackVec.
+    ///
+    /// This is like calling [`Self::as_slice_mut()`](StackVec::as_slice_mut()) and [`Self::spare_capacity_mut()`](StackVec::spare_capacity_mut()) at the same time, allowing new elements to be computed from existing ones while writing them in-place.
+    ///
+    /// # Returns
+    /// A tuple of the initialized elements (as `&mut [T]`) and the spare capacity (as `&mut [MaybeUninit<T>]`).
+    #[inline]
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let len: usize = self.len();
+        let (init, spare): (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) = self.data.split_at_mut(len);
+        // SAFETY: We can safely assume that the first `self.len` [`MaybeUninit`]s are initialized because of our assertion for `self.len`, and `T` and `MaybeUninit<T>` are guaranteed to have the same layout.
+        (unsafe { std::mem::transmute::<&mut [MaybeUninit<T>], &mut [T]>(init) }, spare)
+    }
 
+    /// Forces the length of the StackVec to the given value.
+    ///
+    /// This is a low-level operation that does not touch the elements themselves. It is typically used after initializing elements through [`Self::as_mut_ptr()`](StackVec::as_mut_ptr()), e.g., via FFI. Elements cut off by shortening the StackVec this way are _not_ dropped (see [`Self::truncate()`](StackVec::truncate()) for that).
+    ///
+    /// # Arguments
+    /// - `new_len`: The new length of the StackVec.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `new_len` is at most the capacity of the StackVec; and
+    /// - the first `new_len` elements are initialized.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= LEN, "Cannot set length of StackVec of capacity {} to {}", LEN, new_len);
+        // SAFETY: The caller promises this upholds our `self.len` assertion.
+        self.len = L::from_usize(new_len);
+    }
 
+    /// Returns whether the StackVec has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements can be pushed, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len() >= LEN }
 
+    /// Returns the number of elements that can still be pushed to the StackVec before it is full.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { LEN - self.len() }
 
-/***** LIBRARY *****/
-/// Implements a non-reallocatable, but resizeable, [`Vec`]-like structure that lives in the stack.
-///
-/// This makes allocating it pretty cheap, and even implements [`Copy`]. Even better, basically all functions on it can be `const`.
-///
-/// **Editor's note**: Making `StackVec` [`Copy`] requires conditional [`Drop`] to be safe, which is not possible currently. Aww man that's sad.
-pub struct StackVec<const LEN: usize, T> {
-    /// The data array that we wrap.
-    data: [MaybeUninit<T>; LEN],
-    /// The current number of initialized elements.
+    /// Returns the number of elements this StackVec can store in total.
     ///
-    /// We implement the StackVec such that is upholds the following assertion: the first `len` elements of `data` are initialized.
-    len:  usize,
+    /// Note that, when this number is exceeded, the StackVec does not re-allocate (like a [`Vec`]) but instead throws errors.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
 }
 
-impl<const LEN: usize, T> Default for StackVec<LEN, T> {
+impl<const LEN: usize, T, P: OverflowPolicy, L: LenType> StackVec<LEN, T, P, L> {
+    /// Pushes a new element to the end of the StackVec.
+    ///
+    /// What happens if the StackVec is full is decided by its [`OverflowPolicy`]. By default ([`PanicOnFull`]), it panics; see the other policies for alternatives.
+    ///
+    /// Because it calls into the policy, this function cannot be `const`. Use [`Self::try_push()`](StackVec::try_push()) or [`Self::push_within_capacity()`](StackVec::push_within_capacity()) in `const` contexts instead.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Returns
+    /// Whatever the policy returns; nothing for [`PanicOnFull`].
+    ///
+    /// # Panics
+    /// Under the [`PanicOnFull`] policy, this function can panic if the there isn't enough space in the Vec. You can prevent this by manually checking for space, i.e.,
+    /// ```ignore
+    /// if !stack_vec.is_full() {
+    ///     // Never panics now
+    ///     stack_vec.push(elem);
+    /// }
+    /// ```
     #[inline]
-    fn default() -> Self { Self::new() }
-}
-impl<const LEN: usize, T> StackVec<LEN, T> {
-    /// Constructor for the StackVec that initializes it as empty.
+    #[track_caller]
+    pub fn push(&mut self, elem: T) -> P::Pushed<T> { P::push(self, elem) }
+
+    /// Extends this StackVec with any number of new elements.
     ///
-    /// Note that, by design, StackVecs always have capacity `LEN`.
+    /// The elements are pushed to the end of the vec in-order as yielded by the iterator. What happens if the StackVec becomes full is decided by its [`OverflowPolicy`].
+    ///
+    /// # Arguments
+    /// - `elems`: Something [iterable](IntoIterator) that generates the elements to append.
     ///
     /// # Returns
-    /// A new StackVec with no elements in it.
+    /// Whatever the policy returns; nothing for [`PanicOnFull`].
+    ///
+    /// # Panics
+    /// Under the [`PanicOnFull`] policy, this function can panic if one of the elements causes the StackVec to outgrow its capacity. Being stack-allocated, it cannot be resized.
+    ///
+    /// If the lower bound of the iterator's [`size_hint()`](Iterator::size_hint()) already exceeds the remaining capacity, this panic is raised eagerly, i.e., before any elements are consumed. Otherwise (i.e., if the `size_hint()` underestimates the iterator's length), it is raised lazily when the first element that does not fit is yielded, in which case any elements before it will have been written.
     #[inline]
-    pub const fn new() -> Self {
-        Self {
-            // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still.
-            data: unsafe { MaybeUninit::uninit().assume_init() },
-            len:  0,
+    #[track_caller]
+    pub fn extend(&mut self, elems: impl IntoIterator<Item = T>) -> P::Extended<T> { P::extend(self, elems) }
+}
+
+impl<const LEN: usize, T: Clone, P, L: LenType> StackVec<LEN, T, P, L> {
+    #[cfg(not(feature = "no-panic"))]
+    /// Resizes the StackVec in-place to the given length, cloning the given value to fill any new elements.
+    ///
+    /// If `new_len` is smaller than the current length, the StackVec is simply truncated (see [`Self::truncate()`](StackVec::truncate())).
+    ///
+    /// # Arguments
+    /// - `new_len`: The new length of the StackVec.
+    /// - `value`: The value to fill new elements with.
+    ///
+    /// # Panics
+    /// This function panics if `new_len` exceeds the capacity of the StackVec. In that case, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T) { self.resize_with(new_len, || value.clone()) }
+
+    /// Resizes the StackVec in-place to the given length, cloning the given value to fill any new elements, returning an error if it does not fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::resize()`](StackVec::resize()).
+    ///
+    /// # Arguments
+    /// - `new_len`: The new length of the StackVec.
+    /// - `value`: The value to fill new elements with.
+    ///
+    /// # Errors
+    /// If `new_len` exceeds the capacity of the StackVec, a [`CapacityError`] is returned that carries the number of elements that would not have fit. The vec is untouched in that case.
+    #[inline]
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), CapacityError> { self.try_resize_with(new_len, || value.clone()) }
+
+    /// Overwrites all current elements in the StackVec with clones of the given value.
+    ///
+    /// Only the initialized elements are touched; the length of the StackVec does not change. Use [`Self::fill_to_capacity()`](StackVec::fill_to_capacity()) to fill the entire buffer instead.
+    ///
+    /// # Arguments
+    /// - `value`: The value to fill the elements with.
+    #[inline]
+    pub fn fill(&mut self, value: T) { self.as_slice_mut().fill(value) }
+
+    /// Overwrites the entire StackVec with clones of the given value, growing it to its full capacity.
+    ///
+    /// # Arguments
+    /// - `value`: The value to fill the StackVec with.
+    #[inline]
+    pub fn fill_to_capacity(&mut self, value: T) {
+        self.fill(value.clone());
+        while self.len() < LEN {
+            self.data[self.len()].write(value.clone());
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = L::from_usize(self.len() + 1);
         }
     }
 
-    /// Returns this StackVec as a slice of `T`s.
+    #[cfg(not(feature = "no-panic"))]
+    /// Extends this StackVec with clones of all elements in the given slice.
     ///
-    /// # Returns
-    /// A [`&[T]`] that has the length of this StackVec. Equivalent to `&stack_vec[..]`.
+    /// Unlike [`Self::extend()`](StackVec::extend()), the capacity is checked up-front, so either all elements are appended or none are.
+    ///
+    /// # Arguments
+    /// - `elems`: The slice of elements to clone and append.
+    ///
+    /// # Panics
+    /// This function panics if the elements do not all fit in the StackVec. In that case, the vec is untouched.
     #[inline]
-    pub fn as_slice(&self) -> &[T] { &self[..] }
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, elems: &[T]) {
+        if self.try_extend_from_slice(elems).is_err() {
+            panic!("Cannot extend StackVec of length {} and capacity {} with {} elements", self.len(), LEN, elems.len());
+        }
+    }
 
-    /// Returns this StackVec as a slice of `T`s.
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts clones of all elements in the given slice in the StackVec at a given location.
     ///
-    /// # Returns
-    /// A [`&mut [T]`] that has the length of this StackVec. Equivalent to `&mut stack_vec[..]`.
+    /// See [`Self::insert_many()`](StackVec::insert_many()) for more information.
+    ///
+    /// # Arguments
+    /// - `idx`: The index to insert the first new element in.
+    /// - `elems`: The slice of elements to clone and insert.
+    ///
+    /// # Panics
+    /// This function panics if the given `idx` is out-of-bounds by more than 1, or if there is not enough capacity to store all the new elements. In both cases, the vec is untouched.
     #[inline]
-    pub fn as_slice_mut(&mut self) -> &mut [T] { &mut self[..] }
+    #[track_caller]
+    pub fn insert_slice(&mut self, idx: usize, elems: &[T]) { self.insert_many(idx, elems.iter().cloned()) }
 
-    /// Removes an element from the StackVec.
+    /// Inserts clones of all elements in the given slice in the StackVec at a given location, returning an error if that's not possible.
     ///
-    /// This version preserves the order of non-removed elements. This is at the cost of moving all those other elements one place closer.
+    /// This is the non-panicking counterpart of [`Self::insert_slice()`](StackVec::insert_slice()).
     ///
     /// # Arguments
-    /// - `idx`: The index of the element to return.
+    /// - `idx`: The index to insert the first new element in.
+    /// - `elems`: The slice of elements to clone and insert.
     ///
-    /// # Returns
-    /// The removed element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case.
+    /// # Errors
+    /// An [`InsertError`] is returned if `idx` is out-of-bounds by more than 1 or there is not enough capacity to store all the new elements. The vec is untouched in that case.
     #[inline]
-    pub fn remove(&mut self, idx: usize) -> Option<T> {
-        if idx < self.len {
-            // Move all the elements one to the front. By swapping, we push the "actual" element to the back of the array
-            for i in idx..self.len - 1 {
-                // SAFETY: This will not break our `self.len` assertion, because both indices are guaranteed to be below `self.len`, keeping it intact.
-                self.data.swap(i, i + 1);
-            }
+    pub fn try_insert_slice(&mut self, idx: usize, elems: &[T]) -> Result<(), InsertError<()>> {
+        self.try_insert_many(idx, elems.iter().cloned()).map_err(InsertError::simplify)
+    }
 
-            // Get the element we're talking about, leaving the old value uninitialized
-            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
-            std::mem::swap(&mut res, &mut self.data[self.len - 1]);
-            self.len -= 1;
+    /// Extends this StackVec with clones of all elements in the given slice, returning an error if they do not all fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::extend_from_slice()`](StackVec::extend_from_slice()).
+    ///
+    /// # Arguments
+    /// - `elems`: The slice of elements to clone and append.
+    ///
+    /// # Errors
+    /// If the elements do not all fit, a [`CapacityError`] is returned that carries the number of elements that would not have fit. The vec is untouched in that case.
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, elems: &[T]) -> Result<(), CapacityError> {
+        if self.len() + elems.len() > LEN {
+            return Err(CapacityError::with_excess((), LEN, self.len() + elems.len() - LEN));
+        }
 
-            // OK, return that
-            // SAFETY: We assert throughout that all elements before `self.len` are initialized, which was the case above. We also assert that `idx` is within range.
-            Some(unsafe { res.assume_init() })
-        } else {
-            // Nothing to be done
-            None
+        // Clone the elements over
+        for elem in elems {
+            self.data[self.len()].write(elem.clone());
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = L::from_usize(self.len() + 1);
         }
+        Ok(())
     }
 
-    /// Removes an element from the StackVec, then moves the last element in-place of the removed one.
+    #[cfg(not(feature = "no-panic"))]
+    /// Extends this StackVec with clones of a range of its own elements.
     ///
-    /// This version does _not_ preserve the order of non-removed elements. However, this is more efficient, as it does not require us to move all elements in the array but only the last one.
+    /// # Arguments
+    /// - `src`: The range of elements to clone and append. Must be within the current length.
+    ///
+    /// # Panics
+    /// This function panics if `src` is out-of-bounds or if the cloned elements do not all fit in the StackVec. In both cases, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_within(&mut self, src: impl RangeBounds<usize>) {
+        match self.try_extend_from_within(src) {
+            Ok(()) => {},
+            Err(InsertError::OutOfBounds { idx, len, .. }) => panic!("Range bound {} is out-of-bounds (or reversed) for a StackVec of length {}", idx, len),
+            Err(InsertError::Capacity(err)) => {
+                panic!("Cannot extend StackVec of length {} and capacity {} with {} elements", self.len(), LEN, LEN - self.len() + err.excess())
+            },
+        }
+    }
+
+    /// Extends this StackVec with clones of a range of its own elements, returning an error if that's not possible.
+    ///
+    /// This is the non-panicking counterpart of [`Self::extend_from_within()`](StackVec::extend_from_within()).
     ///
     /// # Arguments
-    /// - `idx`: The index of the element to return.
+    /// - `src`: The range of elements to clone and append. Must be within the current length.
     ///
-    /// # Returns
-    /// The removed element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case.
+    /// # Errors
+    /// If `src` is reversed or out-of-bounds, an [`InsertError::OutOfBounds`] is returned with the offending bound as `idx`. If the cloned elements do not all fit in the StackVec, an [`InsertError::Capacity`] is returned instead. The vec is untouched in both cases.
     #[inline]
-    pub fn swap_remove(&mut self, idx: usize) -> Option<T> {
-        if idx < self.len {
-            // Swap the selected and the last element
-            // The function itself takes care that this doesn't happen needlessly.
-            // SAFETY: This will not break our `self.len` assertion, because both indices are guaranteed to be below `self.len`, keeping it intact.
-            self.data.swap(idx, self.len - 1);
+    pub fn try_extend_from_within(&mut self, src: impl RangeBounds<usize>) -> Result<(), InsertError<()>> {
+        // Resolve the range, treating overflowing bounds as out-of-bounds
+        let len: usize = self.len();
+        let start: usize = match src.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.checked_add(1).ok_or(InsertError::OutOfBounds { idx: *start, len, elem: () })?,
+            Bound::Unbounded => 0,
+        };
+        let end: usize = match src.end_bound() {
+            Bound::Included(end) => end.checked_add(1).ok_or(InsertError::OutOfBounds { idx: *end, len, elem: () })?,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => len,
+        };
+        if end > len {
+            return Err(InsertError::OutOfBounds { idx: end, len, elem: () });
+        }
+        if start > end {
+            return Err(InsertError::OutOfBounds { idx: start, len, elem: () });
+        }
 
-            // Get the element we're talking about, leaving the old value uninitialized
-            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
-            std::mem::swap(&mut res, &mut self.data[self.len - 1]);
-            self.len -= 1;
+        // Check there is enough space
+        if len + (end - start) > LEN {
+            return Err(InsertError::Capacity(CapacityError::with_excess((), LEN, len + (end - start) - LEN)));
+        }
 
-            // OK, return that
-            // SAFETY: We assert throughout that all elements before `self.len` are initialized, which was the case above. We also assert that `idx` is within range.
-            Some(unsafe { res.assume_init() })
-        } else {
-            // Nothing to be done
-            None
+        // Clone the elements over
+        for i in start..end {
+            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and we asserted `end <= self.len`.
+            let elem: T = unsafe { self.data[i].assume_init_ref() }.clone();
+            self.data[self.len()].write(elem);
+            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
+            self.len = L::from_usize(self.len() + 1);
         }
+        Ok(())
     }
+}
 
-    /// Removes the last element from the StackVec.
+impl<const LEN: usize, T: Copy, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Clones the StackVec by copying all of its elements at once.
+    ///
+    /// This is equivalent to [`Clone::clone()`], except that it copies the initialized elements with a single memory copy instead of cloning them one-by-one.
     ///
     /// # Returns
-    /// An element if there was one, or else [`None`].
+    /// A new StackVec with copies of the elements in this one.
     #[inline]
-    pub fn pop(&mut self) -> Option<T> {
-        if self.len > 0 {
-            // Get the element we're talking about, leaving the old value uninitialized
-            let mut res: MaybeUninit<T> = MaybeUninit::uninit();
-            std::mem::swap(&mut res, &mut self.data[self.len - 1]);
-            self.len -= 1;
+    pub fn copy(&self) -> Self {
+        let mut res: Self = Self::new();
+        // SAFETY: Both buffers have room for `LEN` elements and are distinct, and the first `self.len` elements of ours are initialized (as per our assertion).
+        unsafe { std::ptr::copy_nonoverlapping(self.as_ptr(), res.as_mut_ptr(), self.len()) };
+        // SAFETY: We just initialized the first `self.len` elements of `res`.
+        res.len = L::from_usize(self.len());
+        res
+    }
 
-            // OK, return that
-            // SAFETY: We assert throughout that all elements before `self.len` are initialized, which was the case above. We also asserted there was at least one element.
-            Some(unsafe { res.assume_init() })
-        } else {
-            None
+    /// Removes _all_ elements from the StackVec, starting afresh.
+    ///
+    /// This is equivalent to [`Self::clear()`](StackVec::clear()), except that it is `const`, as there is nothing to drop.
+    #[inline]
+    pub const fn clear_copy(&mut self) {
+        // SAFETY: Having no elements trivially upholds our `self.len` assertion. As `T` is `Copy`, not dropping the old ones is fine.
+        self.len = L::ZERO;
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Extends this StackVec with copies of all elements in the given slice, using a single memory copy.
+    ///
+    /// This is equivalent to [`Self::extend_from_slice()`](StackVec::extend_from_slice()), except that it doesn't clone the elements one-by-one.
+    ///
+    /// # Arguments
+    /// - `elems`: The slice of elements to copy and append.
+    ///
+    /// # Panics
+    /// This function panics if the elements do not all fit in the StackVec. In that case, the vec is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_copy_slice(&mut self, elems: &[T]) {
+        if self.try_extend_from_copy_slice(elems).is_err() {
+            panic!("Cannot extend StackVec of length {} and capacity {} with {} elements", self.len(), LEN, elems.len());
         }
     }
 
-    /// Removes _all_ elements from the StackVec, starting afresh.
+    /// Extends this StackVec with copies of all elements in the given slice using a single memory copy, returning an error if they do not all fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::extend_from_copy_slice()`](StackVec::extend_from_copy_slice()).
+    ///
+    /// # Arguments
+    /// - `elems`: The slice of elements to copy and append.
+    ///
+    /// # Errors
+    /// If the elements do not all fit, a [`CapacityError`] is returned that carries the number of elements that would not have fit. The vec is untouched in that case.
     #[inline]
-    pub fn clear(&mut self) {
-        // Drop all elements in ourselves
-        for i in 0..self.len {
-            // SAFETY: OK because `i` is guaranteed to be below `self.len`, and we asserted the first `self.len` elements are ininitialized.
-            unsafe {
-                self.data[i].assume_init_drop();
-            }
+    pub fn try_extend_from_copy_slice(&mut self, elems: &[T]) -> Result<(), CapacityError> {
+        if self.len() + elems.len() > LEN {
+            return Err(CapacityError::with_excess((), LEN, self.len() + elems.len() - LEN));
         }
 
-        // Reset the length to reset the elements
-        self.len = 0;
+        // SAFETY: We just checked there is room for `elems.len()` elements after the first `self.len`, and `elems` cannot overlap with our spare capacity as we borrow ourselves mutably.
+        unsafe { std::ptr::copy_nonoverlapping(elems.as_ptr(), self.as_mut_ptr().add(self.len()), elems.len()) };
+        // SAFETY: This upholds our `self.len` assertion, because we just initialized the elements that we promise will be initialized.
+        self.len = L::from_usize(self.len() + elems.len());
+        Ok(())
     }
 
-    /// Pushes a new element to the end of the StackVec.
+    #[cfg(not(feature = "no-panic"))]
+    /// Copies a range of elements to another place within the StackVec.
+    ///
+    /// This is done with a single memory copy, and the two ranges may overlap. Only the initialized elements can be copied from and to; use [`Self::extend_from_within()`](StackVec::extend_from_within()) to grow the StackVec instead.
     ///
     /// # Arguments
-    /// - `elem`: The new element (of type `T`) to push.
+    /// - `src`: The range of elements to copy.
+    /// - `dest`: The index to copy the first element in `src` to.
     ///
     /// # Panics
-    /// This function can panic if the there isn't enough space in the Vec. You can prevent this by manually checking for space, i.e.,
-    /// ```ignore
-    /// if stack_vec.len() < stack_vec.capacity() {
-    ///     // Never panics now
-    ///     stack_vec.push(elem);
-    /// }
-    /// ```
+    /// This function panics if `src` is out-of-bounds, or if `dest` plus the length of `src` exceeds the length of the StackVec.
     #[inline]
     #[track_caller]
-    pub fn push(&mut self, elem: T) {
-        // Assert there is enough space
-        if self.len < LEN {
-            self.data[self.len].write(elem);
-            // SAFETY: This upholds our `self.len` assertion, because we just initialized the value that we promise will be initialized.
-            self.len += 1;
-        } else {
-            panic!("Cannot push {}th element to StackVec of capacity {}", self.len + 1, LEN);
-        }
-    }
+    pub fn copy_within(&mut self, src: impl RangeBounds<usize>, dest: usize) { self.as_slice_mut().copy_within(src, dest) }
 
-    /// Inserts a new element in the StackVec at a given location.
-    ///
-    /// The insert location must either replace an existing element, or be exactly after the last element. Anything else is considered out-of-bounds.
+    #[cfg(feature = "no-panic")]
+    /// Copies a range of elements to another place within the StackVec, doing nothing if either range is out-of-bounds.
     ///
-    /// The replaced element and all elements after it are pushed one space back to preserve array order.
+    /// This is the version of this function under the `no-panic` feature. It shadows [`<[T]>::copy_within()`](slice::copy_within()), which would otherwise still be reachable through [`Deref`] and panic. Use [`Self::try_copy_within()`](StackVec::try_copy_within()) to find out whether the elements were copied.
     ///
     /// # Arguments
-    /// - `idx`: The index to insert the new element in.
-    /// - `elem`: The new element to insert.
+    /// - `src`: The range of elements to copy.
+    /// - `dest`: The index to copy the first element in `src` to.
+    #[inline]
+    pub fn copy_within(&mut self, src: impl RangeBounds<usize>, dest: usize) { self.try_copy_within(src, dest); }
+
+    /// Copies a range of elements to another place within the StackVec, if both ranges are within bounds.
     ///
-    /// # Panic
-    /// This function panics if the given `idx` is out-of-bounds by more than 1 (i.e., one place outside of the current length is OK, emulating a [`Self::push()`](StackVec::push())).
+    /// # Arguments
+    /// - `src`: The range of elements to copy.
+    /// - `dest`: The index to copy the first element in `src` to.
     ///
-    /// Another panic case is if there is not enough capacity to store the extra element. You can prevent this by manually checking for space, i.e.,
-    /// ```ignore
-    /// if stack_vec.len() < stack_vec.capacity() {
-    ///     // Never panics now
-    ///     stack_vec.push(elem);
-    /// }
-    /// ```
+    /// # Returns
+    /// True if the elements were copied, or false if `src` is out-of-bounds or `dest` plus the length of `src` exceeds the length of the StackVec. The vec is untouched in that case.
     #[inline]
-    #[track_caller]
-    pub fn insert(&mut self, idx: usize, elem: T) {
-        // Assert there is enough space
-        if self.len < LEN {
-            // Assert the index is within bounds
-            if idx <= LEN {
-                // Push all elements one further
-                for i in (idx + 1..=self.len).rev() {
-                    // SAFETY: This temporarily BREAKS our `self.len` assertion, because we push the uninitialized element at `self.len` forward to below the boundary.
-                    //         This will, however, be remedied below.
-                    self.data.swap(i, i - 1);
-                }
-
-                // Now insert the element
-                // SAFETY: This restores our `self.len` assertion, because we initialize the only uninitialized element.
-                self.data[idx].write(elem);
-                // SAFETY: This is OK, because we swapped the uninitialized space at the end for the then-last element.
-                self.len += 1;
-            } else {
-                panic!("Inserting at index {} is out-of-bounds for StackVec of length {}", idx, self.len);
-            }
-        } else {
-            panic!("Cannot push {}th element to StackVec of capacity {}", self.len + 1, LEN);
+    pub fn try_copy_within(&mut self, src: impl RangeBounds<usize>, dest: usize) -> bool {
+        // Resolve the range, treating overflowing bounds as out-of-bounds
+        let start: Option<usize> = match src.start_bound() {
+            Bound::Included(start) => Some(*start),
+            Bound::Excluded(start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end: Option<usize> = match src.end_bound() {
+            Bound::Included(end) => end.checked_add(1),
+            Bound::Excluded(end) => Some(*end),
+            Bound::Unbounded => Some(self.len()),
+        };
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= self.len() && dest <= self.len() - (end - start) => {
+                // NOTE: Can't panic, as we just checked both ranges
+                self.as_slice_mut().copy_within(start..end, dest);
+                true
+            },
+            _ => false,
         }
     }
+}
 
-    /// Extends this StackVec with any number of new elements.
+impl<const LEN: usize, T: PartialEq, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Removes consecutive repeated elements from the StackVec.
+    ///
+    /// Of every run of equal elements, only the first is kept. The remaining elements are moved to the front, preserving their order. If the StackVec is sorted, this removes all duplicates.
+    #[inline]
+    pub fn dedup(&mut self) { self.dedup_by(|lhs, rhs| lhs == rhs) }
+
+    /// Returns whether the StackVec contains an element equal to the given one.
     ///
-    /// The elements are pushed to the end of the vec in-order as yielded by the iterator.
+    /// Note that this cannot be `const`, as trait methods like [`PartialEq::eq()`] cannot be called in `const` contexts on stable Rust.
     ///
     /// # Arguments
-    /// - `elems`: Something [iterable](IntoIterator) that generates the elements to append.
+    /// - `elem`: The element to search for.
     ///
-    /// # Panics
-    /// This function can panic if one of the elements causes the StackVec to outgrow its capacity. Being stack-allocated, it cannot be resized.
+    /// # Returns
+    /// True if at least one element in the StackVec equals `elem`, false otherwise.
+    #[inline]
+    pub fn contains(&self, elem: &T) -> bool { self.as_slice().contains(elem) }
+
+    /// Removes the first element from the StackVec that is equal to the given one.
+    ///
+    /// This version preserves the order of non-removed elements, like [`Self::remove()`](StackVec::remove()).
+    ///
+    /// # Arguments
+    /// - `elem`: The element to search for.
     ///
-    /// Note that this panic is raised lazily, i.e., if it occurs, any elements that may have fit will have been written.
+    /// # Returns
+    /// The removed element, or else [`None`] if no element equals `elem`. The vec is guaranteed to be untouched, in that case.
     #[inline]
-    #[track_caller]
-    pub fn extend(&mut self, elems: impl IntoIterator<Item = T>) {
-        // No need to reserve, we have all the capacity we ever get
-        for elem in elems {
-            self.push(elem);
-        }
+    pub fn remove_item(&mut self, elem: &T) -> Option<T> {
+        let idx: usize = self.iter().position(|other| other == elem)?;
+        self.remove(idx)
     }
+}
 
-    // /// Returns the number of elements stored in the StackVec.
-    // #[inline]
-    // pub const fn len(&self) -> usize { self.len }
+impl<const LEN: usize, T: Default, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Moves an element out of the StackVec, leaving [`T::default()`](Default::default()) in its place.
+    ///
+    /// Unlike [`Self::remove()`](StackVec::remove()), this does not move any other elements.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to take.
+    ///
+    /// # Returns
+    /// The taken element, or else [`None`] if the `idx` is out-of-bounds. The vec is guaranteed to be untouched, in that case.
+    #[inline]
+    pub fn take(&mut self, idx: usize) -> Option<T> { self.get_mut(idx).map(std::mem::take) }
+}
 
-    // /// Returns whether any elements are stored in the StackVec at all.
-    // ///
-    // /// # Returns
-    // /// True if there are 0 elements, false if there is at least 1.
-    // #[inline]
-    // pub const fn is_empty(&self) -> bool { self.len == 0 }
+impl<const LEN: usize, T: Ord, P, L: LenType> StackVec<LEN, T, P, L> {
+    /// Inserts a new element in a sorted StackVec such that it stays sorted.
+    ///
+    /// The insertion point is found using a binary search. If there are elements equal to `elem`, it is inserted after them.
+    ///
+    /// Note that the StackVec is assumed to be sorted. If it isn't, the insertion point is unspecified (but it is still inserted).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted.
+    ///
+    /// # Errors
+    /// If the StackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn insert_sorted(&mut self, elem: T) -> Result<usize, CapacityError<T>> { self.insert_sorted_by(elem, T::cmp) }
+}
 
-    /// Returns the number of elements this StackVec can store in total.
+impl<const LEN: usize, T, U, P, L: LenType> StackVec<LEN, (T, U), P, L> {
+    /// Splits a StackVec of pairs into two StackVecs of the same capacity.
     ///
-    /// Note that, when this number is exceeded, the StackVec does not re-allocate (like a [`Vec`]) but instead throws errors.
+    /// This is the reverse of [`Self::zip()`](StackVec::zip()). Unlike [`Iterator::unzip()`], it does not check the capacity for every element.
+    ///
+    /// # Returns
+    /// A tuple of a StackVec with the first element of every pair, and a StackVec with the second, both in order.
     #[inline]
-    pub const fn capacity(&self) -> usize { LEN }
+    pub fn unzip(self) -> (StackVec<LEN, T, P, L>, StackVec<LEN, U, P, L>) {
+        let mut lhs: StackVec<LEN, T, P, L> = StackVec::new();
+        let mut rhs: StackVec<LEN, U, P, L> = StackVec::new();
+        for (i, (a, b)) in self.into_iter().enumerate() {
+            // NOTE: Can't go out-of-bounds, as there are at most `LEN` pairs
+            lhs.data[i].write(a);
+            rhs.data[i].write(b);
+            // SAFETY: This upholds our `self.len` assertion for both, because we just initialized the values that we promise will be initialized.
+            lhs.len = L::from_usize(i + 1);
+            rhs.len = L::from_usize(i + 1);
+        }
+        (lhs, rhs)
+    }
 }
 
 // Things we usually derive, but require some special attention
-impl<const LEN: usize, T: Clone> Clone for StackVec<LEN, T> {
+impl<const LEN: usize, T: Clone, P, L: LenType> Clone for StackVec<LEN, T, P, L> {
     #[inline]
     fn clone(&self) -> Self {
         // Clone only initialized elements
         // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still.
         let mut data: [MaybeUninit<T>; LEN] = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            data[i] = MaybeUninit::new(unsafe { self.data[i].assume_init_ref() }.clone());
+        for (dst, src) in data.iter_mut().zip(self.iter()) {
+            *dst = MaybeUninit::new(src.clone());
         }
 
         // OK, create Self with that
-        Self { data, len: self.len }
+        Self { data, len: self.len, policy: PhantomData }
     }
 }
-// NOTE: Can re-enable once/if [`Drop`] becomes conditional.
+// NOTE: Can re-enable once/if [`Drop`] becomes conditional. Until then, there's [`CopyStackVec`].
 // impl<const LEN: usize, T: Copy> Copy for StackVec<LEN, T> {}
-impl<const LEN: usize, T: Debug> Debug for StackVec<LEN, T> {
+impl<const LEN: usize, T: Debug, P, L: LenType> Debug for StackVec<LEN, T, P, L> {
     #[inline]
-    fn fmt(&self, f: &mut Formatter) -> FResult {
-        let mut vec = f.debug_list();
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            vec.entry(unsafe { self.data[i].assume_init_ref() });
-        }
-        vec.finish()
-    }
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_slice(), f) }
 }
 // The drop ruining the [`Copy`] implementation :/
-impl<const LEN: usize, T> Drop for StackVec<LEN, T> {
+impl<const LEN: usize, T, P, L: LenType> Drop for StackVec<LEN, T, P, L> {
     #[inline]
     fn drop(&mut self) {
-        for i in 0..self.len {
+        // Elements without drop glue can simply be forgotten
+        if !std::mem::needs_drop::<T>() {
+            return;
+        }
+        for i in 0..self.len() {
             // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
             unsafe { self.data[i].assume_init_drop() };
         }
     }
 }
-impl<const LEN: usize, T: Eq> Eq for StackVec<LEN, T> {}
-impl<const LEN: usize, T: PartialEq> PartialEq for StackVec<LEN, T> {
+impl<const LEN: usize, T: Eq, P, L: LenType> Eq for StackVec<LEN, T, P, L> {}
+impl<const LEN: usize, T: Hash, P, L: LenType> Hash for StackVec<LEN, T, P, L> {
+    /// Hashes the StackVec like a slice of its elements, as required by its [`Borrow<[T]>`](Borrow) implementation.
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        if self.len != other.len {
-            return false;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted `self.len == other.len` (so the property extends to the other vec too).
-            let rhs: &T = unsafe { other.data[i].assume_init_ref() };
-
-            // Now we can compare
-            if lhs != rhs {
-                return false;
-            }
-        }
-        true
-    }
-
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq, P, P2, L: LenType, L2: LenType> PartialEq<StackVec<LEN2, T, P2, L2>> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn ne(&self, other: &Self) -> bool {
-        if self.len != other.len {
-            return true;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted `self.len == other.len` (so the property extends to the other vec too).
-            let rhs: &T = unsafe { other.data[i].assume_init_ref() };
-
-            // Now we can compare
-            if lhs == rhs {
-                return false;
-            }
-        }
-        true
-    }
+    fn eq(&self, other: &StackVec<LEN2, T, P2, L2>) -> bool { self.as_slice() == other.as_slice() }
 }
-impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<[T; LEN2]> for StackVec<LEN, T> {
+impl<const LEN: usize, const LEN2: usize, T: PartialEq, P, L: LenType> PartialEq<[T; LEN2]> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn eq(&self, other: &[T; LEN2]) -> bool {
-        if self.len != LEN2 {
-            return false;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We already know that `other` has at least this much elements
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs != rhs {
-                return false;
-            }
-        }
-        true
-    }
-
+    fn eq(&self, other: &[T; LEN2]) -> bool { self.as_slice() == other.as_slice() }
+}
+impl<'s, const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<&'s [T]> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn ne(&self, other: &[T; LEN2]) -> bool {
-        if self.len != LEN2 {
-            return true;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted `self.len == other.len` (so the property extends to the other vec too).
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs == rhs {
-                return false;
-            }
-        }
-        true
-    }
+    fn eq(&self, other: &&'s [T]) -> bool { self.as_slice() == *other }
 }
-impl<'s, const LEN: usize, T: PartialEq> PartialEq<&'s [T]> for StackVec<LEN, T> {
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<Vec<T>> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn eq(&self, other: &&'s [T]) -> bool {
-        if self.len != other.len() {
-            return false;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We already know that `other` has at least this much elements
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs != rhs {
-                return false;
-            }
-        }
-        true
-    }
-
+    fn eq(&self, other: &Vec<T>) -> bool { self.as_slice() == other.as_slice() }
+}
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<[T]> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn ne(&self, other: &&'s [T]) -> bool {
-        if self.len != other.len() {
-            return true;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted `self.len == other.len` (so the property extends to the other vec too).
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs == rhs {
-                return false;
-            }
-        }
-        true
-    }
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
 }
-impl<const LEN: usize, T: PartialEq> PartialEq<Vec<T>> for StackVec<LEN, T> {
+impl<'s, const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<&'s mut [T]> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn eq(&self, other: &Vec<T>) -> bool {
-        if self.len != other.len() {
-            return false;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We already know that `other` has at least this much elements
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs != rhs {
-                return false;
-            }
-        }
-        true
-    }
-
+    fn eq(&self, other: &&'s mut [T]) -> bool { self.as_slice() == *other }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq, P, L: LenType> PartialEq<StackVec<LEN, T, P, L>> for [T; LEN2] {
     #[inline]
-    fn ne(&self, other: &Vec<T>) -> bool {
-        if self.len != other.len() {
-            return true;
-        }
-        for i in 0..self.len {
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted `self.len == other.len` (so the property extends to the other vec too).
-            let rhs: &T = unsafe { other.get_unchecked(i) };
-
-            // Now we can compare
-            if lhs == rhs {
-                return false;
-            }
-        }
-        true
-    }
+    fn eq(&self, other: &StackVec<LEN, T, P, L>) -> bool { self.as_slice() == other.as_slice() }
 }
-impl<const LEN: usize, T: Ord> Ord for StackVec<LEN, T> {
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<StackVec<LEN, T, P, L>> for [T] {
     #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        // SAFETY: We can `unwrap()` here because [`Ord`] requires that `T`'s [`PartialOrd`] implementation always returns [`Some`].
-        self.partial_cmp(other)
-            .expect("Broken promise from 'T'; T implementing 'Ord' requires that its 'PartialOrd' implementation always returns 'Some'")
-    }
+    fn eq(&self, other: &StackVec<LEN, T, P, L>) -> bool { self == other.as_slice() }
 }
-impl<const LEN: usize, T: PartialOrd> PartialOrd for StackVec<LEN, T> {
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<StackVec<LEN, T, P, L>> for &[T] {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        for i in 0.. {
-            // See if we're still into range
-            if i >= self.len && i >= other.len {
-                // They really are the same; stop here
-                break;
-            } else if i < self.len {
-                // `self` is shorter than `other`, which tells us to consider `self` the lesser
-                // See 'https://doc.rust-lang.org/std/cmp/trait.Ord.html#lexicographical-comparison'
-                return Some(Ordering::Less);
-            } else if i < other.len {
-                // `self` is longer than `other`, which tells us to consider `self` the greater
-                // See 'https://doc.rust-lang.org/std/cmp/trait.Ord.html#lexicographical-comparison'
-                return Some(Ordering::Greater);
-            }
-
-            // Otherwise, the ordering depends on the elements
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted that `i` is shorted than `self.len`.
-            let lhs: &T = unsafe { self.data[i].assume_init_ref() };
-            // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and the fact that we asserted that `i` is shorted than `other.len`.
-            let rhs: &T = unsafe { other.data[i].assume_init_ref() };
-
-            // Compare them
-            match lhs.partial_cmp(rhs) {
-                Some(Ordering::Greater) => return Some(Ordering::Greater),
-                Some(Ordering::Less) => return Some(Ordering::Less),
-                None => return None,
-
-                // Only if they are explicitly equal, we continue
-                Some(Ordering::Equal) => continue,
-            }
-        }
-
-        // If we got here, all elements & length are equal
-        Some(Ordering::Equal)
-    }
+    fn eq(&self, other: &StackVec<LEN, T, P, L>) -> bool { *self == other.as_slice() }
+}
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<StackVec<LEN, T, P, L>> for &mut [T] {
+    #[inline]
+    fn eq(&self, other: &StackVec<LEN, T, P, L>) -> bool { *self == other.as_slice() }
+}
+impl<const LEN: usize, T: PartialEq, P, L: LenType> PartialEq<StackVec<LEN, T, P, L>> for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &StackVec<LEN, T, P, L>) -> bool { self.as_slice() == other.as_slice() }
+}
+impl<const LEN: usize, T: Ord, P, L: LenType> Ord for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering { self.as_slice().cmp(other.as_slice()) }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialOrd, P, P2, L: LenType, L2: LenType> PartialOrd<StackVec<LEN2, T, P2, L2>> for StackVec<LEN, T, P, L> {
+    /// Compares the StackVecs [lexicographically](https://doc.rust-lang.org/std/cmp/trait.Ord.html#lexicographical-comparison), like [`Vec`]s and slices are compared.
+    ///
+    /// In particular, if one StackVec is a prefix of the other, it is considered the lesser one.
+    #[inline]
+    fn partial_cmp(&self, other: &StackVec<LEN2, T, P2, L2>) -> Option<Ordering> { self.as_slice().partial_cmp(other.as_slice()) }
 }
 
 // Deref
-impl<const LEN: usize, T> Deref for StackVec<LEN, T> {
+impl<const LEN: usize, T, P, L: LenType> Deref for StackVec<LEN, T, P, L> {
     type Target = [T];
 
     #[inline]
     fn deref(&self) -> &Self::Target { self.as_slice() }
 }
-impl<const LEN: usize, T> DerefMut for StackVec<LEN, T> {
+impl<const LEN: usize, T, P, L: LenType> DerefMut for StackVec<LEN, T, P, L> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target { self.as_slice_mut() }
 }
 
+// Borrowing
+impl<const LEN: usize, T, P, L: LenType> AsRef<[T]> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+impl<const LEN: usize, T, P, L: LenType> AsMut<[T]> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+impl<const LEN: usize, T, P, L: LenType> Borrow<[T]> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self.as_slice() }
+}
+impl<const LEN: usize, T, P, L: LenType> BorrowMut<[T]> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] { self.as_slice_mut() }
+}
+
 // Indexing
-impl<const LEN: usize, T> Index<usize> for StackVec<LEN, T> {
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T, P, L: LenType> Index<usize> for StackVec<LEN, T, P, L> {
     type Output = T;
 
     #[inline]
     #[track_caller]
     fn index(&self, index: usize) -> &Self::Output {
-        if index < self.len {
+        if index < self.len() {
             // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and that `idx` is surely within range of `self.len`.
             unsafe { self.data[index].assume_init_ref() }
         } else {
-            panic!("Index {} is out-of-bounds for a StackVec of length {}", index, self.len);
+            panic!("Index {} is out-of-bounds for a StackVec of length {}", index, self.len());
         }
     }
 }
-impl<const LEN: usize, T> IndexMut<usize> for StackVec<LEN, T> {
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T, P, L: LenType> IndexMut<usize> for StackVec<LEN, T, P, L> {
     #[inline]
     #[track_caller]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index < self.len {
+        if index < self.len() {
             // SAFETY: We use our assertion for `self.len` that the first `self.len` elements are always initialized, and that `idx` is surely within range of `self.len`.
             unsafe { self.data[index].assume_init_mut() }
         } else {
-            panic!("Index {} is out-of-bounds for a StackVec of length {}", index, self.len);
+            panic!("Index {} is out-of-bounds for a StackVec of length {}", index, self.len());
         }
     }
 }
-index_range_impl!(Range<usize>, |len: usize, index: Range<usize>| {
+index_range_impl!(#[cfg(not(feature = "no-panic"))] Range<usize>, |len: usize, index: Range<usize>| {
     if index.start >= len {
         panic!("Range start {} (inclusive) is out-of-bounds for a StackVec of length {}", index.start, len);
     }
@@ -740,7 +4885,7 @@ index_range_impl!(Range<usize>, |len: usize, index: Range<usize>| {
     }
     (index.start, index.end)
 });
-index_range_impl!(RangeInclusive<usize>, |len: usize, index: RangeInclusive<usize>| {
+index_range_impl!(#[cfg(not(feature = "no-panic"))] RangeInclusive<usize>, |len: usize, index: RangeInclusive<usize>| {
     let (start, end): (usize, usize) = (*index.start(), *index.end());
     if start >= len {
         panic!("Range start {} (inclusive) is out-of-bounds for a StackVec of length {}", start, len);
@@ -750,19 +4895,19 @@ index_range_impl!(RangeInclusive<usize>, |len: usize, index: RangeInclusive<usiz
     }
     (start, end + 1)
 });
-index_range_impl!(RangeFrom<usize>, |len: usize, index: RangeFrom<usize>| {
+index_range_impl!(#[cfg(not(feature = "no-panic"))] RangeFrom<usize>, |len: usize, index: RangeFrom<usize>| {
     if index.start >= len {
         panic!("Range start {} (inclusive) is out-of-bounds for a StackVec of length {}", index.start, len);
     }
     (index.start, len)
 });
-index_range_impl!(RangeTo<usize>, |len: usize, index: RangeTo<usize>| {
+index_range_impl!(#[cfg(not(feature = "no-panic"))] RangeTo<usize>, |len: usize, index: RangeTo<usize>| {
     if index.end > len {
         panic!("Range end {} (exclusive) is out-of-bounds for a StackVec of length {}", index.end, len);
     }
     (0, index.end)
 });
-index_range_impl!(RangeToInclusive<usize>, |len: usize, index: RangeToInclusive<usize>| {
+index_range_impl!(#[cfg(not(feature = "no-panic"))] RangeToInclusive<usize>, |len: usize, index: RangeToInclusive<usize>| {
     if index.end >= len {
         panic!("Range end {} (inclusive) is out-of-bounds for a StackVec of length {}", index.end, len);
     }
@@ -771,24 +4916,24 @@ index_range_impl!(RangeToInclusive<usize>, |len: usize, index: RangeToInclusive<
 index_range_impl!(RangeFull, |len: usize, _index: RangeFull| { (0, len) });
 
 // Iteration
-impl<const LEN: usize, T> IntoIterator for StackVec<LEN, T> {
-    type IntoIter = IntoIter<LEN, T>;
+impl<const LEN: usize, T, P, L: LenType> IntoIterator for StackVec<LEN, T, P, L> {
+    type IntoIter = IntoIter<LEN, T, L>;
     type Item = T;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        let end: usize = self.len;
-        IntoIter { vec: self, i: 0, end }
+        let end: usize = self.len();
+        IntoIter { vec: self.with_policy(), i: 0, end }
     }
 }
-impl<'s, const LEN: usize, T> IntoIterator for &'s StackVec<LEN, T> {
+impl<'s, const LEN: usize, T, P, L: LenType> IntoIterator for &'s StackVec<LEN, T, P, L> {
     type IntoIter = std::slice::Iter<'s, T>;
     type Item = &'s T;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl<'s, const LEN: usize, T> IntoIterator for &'s mut StackVec<LEN, T> {
+impl<'s, const LEN: usize, T, P, L: LenType> IntoIterator for &'s mut StackVec<LEN, T, P, L> {
     type IntoIter = std::slice::IterMut<'s, T>;
     type Item = &'s mut T;
 
@@ -797,8 +4942,9 @@ impl<'s, const LEN: usize, T> IntoIterator for &'s mut StackVec<LEN, T> {
 }
 
 // From
-impl<const LEN: usize, T> FromIterator<T> for StackVec<LEN, T> {
+impl<const LEN: usize, T, P: OverflowPolicy<Extended<T> = ()>, L: LenType> FromIterator<T> for StackVec<LEN, T, P, L> {
     #[inline]
+    #[track_caller]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         // Create a new stack, extend, enjoy
         let mut stack: Self = Self::new();
@@ -806,26 +4952,56 @@ impl<const LEN: usize, T> FromIterator<T> for StackVec<LEN, T> {
         stack
     }
 }
-impl<const LEN: usize, const LEN2: usize, T> From<[T; LEN2]> for StackVec<LEN, T> {
+impl<const LEN: usize, const LEN2: usize, T, P, L: LenType> From<[T; LEN2]> for StackVec<LEN, T, P, L> {
+    #[inline]
+    fn from(value: [T; LEN2]) -> Self { Self::from_array(value) }
+}
+
+// Extend
+impl<const LEN: usize, T, P: OverflowPolicy<Extended<T> = ()>, L: LenType> Extend<T> for StackVec<LEN, T, P, L> {
+    #[inline]
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) { P::extend(self, iter) }
+}
+impl<'a, const LEN: usize, T: 'a + Copy, P: OverflowPolicy<Extended<T> = ()>, L: LenType> Extend<&'a T> for StackVec<LEN, T, P, L> {
     #[inline]
-    fn from(value: [T; LEN2]) -> Self { Self::from_iter(value) }
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) { P::extend(self, iter.into_iter().copied()) }
 }
-impl<const LEN: usize, T: Clone> From<&[T]> for StackVec<LEN, T> {
+
+// TryFrom
+impl<const LEN: usize, T: Clone, P, L: LenType> TryFrom<&[T]> for StackVec<LEN, T, P, L> {
+    type Error = CapacityError;
+
     #[inline]
-    fn from(value: &[T]) -> Self { Self::from_iter(value.into_iter().cloned()) }
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut stack: Self = Self::new();
+        stack.try_extend_from_slice(value)?;
+        Ok(stack)
+    }
 }
-impl<const LEN: usize, T> From<Vec<T>> for StackVec<LEN, T> {
+impl<const LEN: usize, T, P, L: LenType> TryFrom<Vec<T>> for StackVec<LEN, T, P, L> {
+    type Error = CapacityError<Vec<T>>;
+
     #[inline]
-    fn from(value: Vec<T>) -> Self { Self::from_iter(value) }
+    fn try_from(value: Vec<T>) -> Result<Self, Self::Error> {
+        if value.len() > LEN {
+            let excess: usize = value.len() - LEN;
+            return Err(CapacityError::with_excess(value, LEN, excess));
+        }
+        let mut stack: Self = Self::new();
+        stack.extend_or_panic(value);
+        Ok(stack)
+    }
 }
 
 // Into
-impl<const LEN: usize, T> From<StackVec<LEN, T>> for Vec<T> {
+impl<const LEN: usize, T, P, L: LenType> From<StackVec<LEN, T, P, L>> for Vec<T> {
     #[inline]
-    fn from(mut value: StackVec<LEN, T>) -> Self {
+    fn from(mut value: StackVec<LEN, T, P, L>) -> Self {
         // Move the values that we haven't moved before
-        let mut res: Vec<T> = Vec::with_capacity(value.len);
-        for i in 0..value.len {
+        let mut res: Vec<T> = Vec::with_capacity(value.len());
+        for i in 0..value.len() {
             // Get the value out
             let mut elem: MaybeUninit<T> = MaybeUninit::uninit();
             std::mem::swap(&mut elem, &mut value.data[i]);
@@ -840,3 +5016,128 @@ impl<const LEN: usize, T> From<StackVec<LEN, T>> for Vec<T> {
         res
     }
 }
+impl<const LEN: usize, T, P, L: LenType> From<StackVec<LEN, T, P, L>> for Box<[T]> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P, L>) -> Self { value.into_boxed_slice() }
+}
+impl<const LEN: usize, T, P, L: LenType> From<StackVec<LEN, T, P, L>> for VecDeque<T> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P, L>) -> Self { VecDeque::from(value.into_vec()) }
+}
+impl<const LEN: usize, T, P, L: LenType> TryFrom<StackVec<LEN, T, P, L>> for [T; LEN] {
+    type Error = StackVec<LEN, T, P, L>;
+
+    #[inline]
+    fn try_from(value: StackVec<LEN, T, P, L>) -> Result<Self, Self::Error> { value.into_inner() }
+}
+
+// Byte search
+impl<const LEN: usize, P, L: LenType> StackVec<LEN, u8, P, L> {
+    /// Returns whether the StackVec contains the given byte.
+    ///
+    /// With the `memchr` feature, this uses [`memchr`](https://docs.rs/memchr)'s SIMD-accelerated search.
+    ///
+    /// # Arguments
+    /// - `byte`: The byte to search for.
+    ///
+    /// # Returns
+    /// True if at least one byte in the StackVec equals `byte`, false otherwise.
+    #[inline]
+    pub fn contains_byte(&self, byte: u8) -> bool { self.find_byte(byte).is_some() }
+
+    /// Returns the index of the first occurrence of the given byte.
+    ///
+    /// With the `memchr` feature, this uses [`memchr`](https://docs.rs/memchr)'s SIMD-accelerated search.
+    ///
+    /// # Arguments
+    /// - `byte`: The byte to search for.
+    ///
+    /// # Returns
+    /// The index of the first byte equal to `byte`, or [`None`] if there is none.
+    #[inline]
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        return memchr::memchr(byte, self.as_slice());
+        #[cfg(not(feature = "memchr"))]
+        return self.iter().position(|b| *b == byte);
+    }
+
+    /// Returns the index of the last occurrence of the given byte.
+    ///
+    /// With the `memchr` feature, this uses [`memchr`](https://docs.rs/memchr)'s SIMD-accelerated search.
+    ///
+    /// # Arguments
+    /// - `byte`: The byte to search for.
+    ///
+    /// # Returns
+    /// The index of the last byte equal to `byte`, or [`None`] if there is none.
+    #[inline]
+    pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        return memchr::memrchr(byte, self.as_slice());
+        #[cfg(not(feature = "memchr"))]
+        return self.iter().rposition(|b| *b == byte);
+    }
+
+    /// Returns the index of the first occurrence of the given byte string.
+    ///
+    /// With the `memchr` feature, this uses [`memchr::memmem`](https://docs.rs/memchr/latest/memchr/memmem)'s SIMD-accelerated search.
+    ///
+    /// # Arguments
+    /// - `needle`: The bytes to search for. An empty needle is always found at index 0.
+    ///
+    /// # Returns
+    /// The index at which `needle` first starts, or [`None`] if it doesn't occur.
+    #[inline]
+    pub fn find_bytes(&self, needle: &[u8]) -> Option<usize> {
+        #[cfg(feature = "memchr")]
+        return memchr::memmem::find(self.as_slice(), needle);
+        #[cfg(not(feature = "memchr"))]
+        return if needle.is_empty() { Some(0) } else { self.windows(needle.len()).position(|window| window == needle) };
+    }
+}
+
+// I/O
+impl<const LEN: usize, P, L: LenType> StackVec<LEN, u8, P, L> {
+    /// Appends as many bytes of `buf` as fit in the StackVec.
+    ///
+    /// # Returns
+    /// The number of bytes that were appended.
+    #[inline]
+    pub(crate) fn write_truncated(&mut self, buf: &[u8]) -> usize {
+        let n: usize = buf.len().min(LEN - self.len());
+        // SAFETY: We only write the first `n` bytes of the spare capacity, which we asserted fit, and `buf` cannot overlap with our own buffer because we borrow ourselves mutably.
+        unsafe { std::ptr::copy_nonoverlapping(buf.as_ptr(), self.as_mut_ptr().add(self.len()), n) };
+        // SAFETY: We just initialized the next `n` elements.
+        self.len = L::from_usize(self.len() + n);
+        n
+    }
+}
+impl<const LEN: usize, P, L: LenType> Write for StackVec<LEN, u8, P, L> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // `Write::write_all()` turns writing nothing into an error for us
+        Ok(self.write_truncated(buf))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+impl<const LEN: usize, P, L: LenType> FWrite for StackVec<LEN, u8, P, L> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> FResult {
+        // Don't write anything if it doesn't fit, lest we cut a character in half
+        self.try_extend_from_copy_slice(s.as_bytes()).map_err(|_| FError)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments) -> FResult {
+        // Roll back the pieces that did fit if a later one doesn't, so the whole call is all-or-nothing
+        let len: usize = self.len();
+        let res: FResult = std::fmt::write(self, args);
+        if res.is_err() {
+            self.truncate(len);
+        }
+        res
+    }
+}
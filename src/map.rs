@@ -0,0 +1,647 @@
+//  Description:
+//!   Implements a [`StackMap`], a small fixed-capacity map that lives in the
+//!   stack.
+//
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::iter::FusedIterator;
+#[cfg(not(feature = "no-panic"))]
+use std::ops::Index;
+
+use crate::{CapacityError, IntoIter, StackVec};
+
+
+/***** ITERATORS *****/
+/// Iterates over the entries of a [`StackMap`] by reference.
+pub struct MapIter<'m, K, V> {
+    /// The entries that we iterate over.
+    iter: std::slice::Iter<'m, (K, V)>,
+}
+impl<'m, K, V> Clone for MapIter<'m, K, V> {
+    #[inline]
+    fn clone(&self) -> Self { Self { iter: self.iter.clone() } }
+}
+impl<'m, K, V> Iterator for MapIter<'m, K, V> {
+    type Item = (&'m K, &'m V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.iter.next().map(|(key, value)| (key, value)) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+impl<'m, K, V> DoubleEndedIterator for MapIter<'m, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.iter.next_back().map(|(key, value)| (key, value)) }
+}
+impl<'m, K, V> ExactSizeIterator for MapIter<'m, K, V> {}
+impl<'m, K, V> FusedIterator for MapIter<'m, K, V> {}
+
+/// Iterates over the entries of a [`StackMap`] by reference, with mutable access to the values.
+pub struct MapIterMut<'m, K, V> {
+    /// The entries that we iterate over.
+    iter: std::slice::IterMut<'m, (K, V)>,
+}
+impl<'m, K, V> Iterator for MapIterMut<'m, K, V> {
+    type Item = (&'m K, &'m mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.iter.next().map(|(key, value)| (&*key, value)) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
+}
+impl<'m, K, V> DoubleEndedIterator for MapIterMut<'m, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.iter.next_back().map(|(key, value)| (&*key, value)) }
+}
+impl<'m, K, V> ExactSizeIterator for MapIterMut<'m, K, V> {}
+impl<'m, K, V> FusedIterator for MapIterMut<'m, K, V> {}
+
+
+
+
+
+/***** ENTRIES *****/
+/// A view into a single entry of a [`StackMap`], which may either be occupied or vacant.
+///
+/// Returned by [`StackMap::entry()`].
+pub enum MapEntry<'m, const LEN: usize, K, V> {
+    /// The key is already in the map.
+    Occupied(OccupiedMapEntry<'m, LEN, K, V>),
+    /// The key is not in the map yet.
+    Vacant(VacantMapEntry<'m, LEN, K, V>),
+}
+impl<'m, const LEN: usize, K, V> MapEntry<'m, LEN, K, V> {
+    /// Returns the key of this entry.
+    ///
+    /// # Returns
+    /// A reference to the key that was looked up.
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Modifies the value in the entry if it is occupied.
+    ///
+    /// # Arguments
+    /// - `f`: A closure that is called with the existing value, if any.
+    ///
+    /// # Returns
+    /// This entry, for chaining.
+    #[inline]
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Returns the value in the entry, inserting the given one if it is vacant.
+    ///
+    /// # Arguments
+    /// - `default`: The value to insert if the entry is vacant.
+    ///
+    /// # Returns
+    /// A mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// This function panics if the entry is vacant and the map is full.
+    #[inline]
+    #[track_caller]
+    pub fn or_insert(self, default: V) -> &'m mut V { self.or_insert_with(|| default) }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Returns the value in the entry, inserting the result of the given closure if it is vacant.
+    ///
+    /// # Arguments
+    /// - `default`: A closure that produces the value to insert. Only called if the entry is vacant.
+    ///
+    /// # Returns
+    /// A mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// This function panics if the entry is vacant and the map is full.
+    #[inline]
+    #[track_caller]
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'m mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Returns the value in the entry, inserting [`V::default()`](Default::default()) if it is vacant.
+    ///
+    /// # Returns
+    /// A mutable reference to the value in the entry.
+    ///
+    /// # Panics
+    /// This function panics if the entry is vacant and the map is full.
+    #[inline]
+    #[track_caller]
+    pub fn or_default(self) -> &'m mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// A view into an entry of a [`StackMap`] whose key is already in it.
+pub struct OccupiedMapEntry<'m, const LEN: usize, K, V> {
+    /// The map that we're an entry of.
+    map: &'m mut StackMap<LEN, K, V>,
+    /// The index of the entry in the map. Always in-bounds.
+    idx: usize,
+}
+impl<'m, const LEN: usize, K, V> OccupiedMapEntry<'m, LEN, K, V> {
+    /// Returns the entry's key and value.
+    #[inline]
+    fn pair(&self) -> &(K, V) {
+        // NOTE: Can't fail, as `self.idx` is in-bounds for as long as we borrow the map
+        &self.map.vec.as_slice()[self.idx]
+    }
+
+    /// Returns the entry's key and value, mutably.
+    #[inline]
+    fn pair_mut(&mut self) -> &mut (K, V) {
+        // NOTE: Can't fail, as `self.idx` is in-bounds for as long as we borrow the map
+        &mut self.map.vec.as_slice_mut()[self.idx]
+    }
+
+    /// Returns the key of this entry.
+    ///
+    /// # Returns
+    /// A reference to the key as it is stored in the map.
+    #[inline]
+    pub fn key(&self) -> &K { &self.pair().0 }
+
+    /// Returns the value of this entry.
+    ///
+    /// # Returns
+    /// A reference to the value.
+    #[inline]
+    pub fn get(&self) -> &V { &self.pair().1 }
+
+    /// Returns the value of this entry, mutably.
+    ///
+    /// # Returns
+    /// A mutable reference to the value, borrowing the entry.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V { &mut self.pair_mut().1 }
+
+    /// Returns the value of this entry, mutably, for as long as the map is borrowed.
+    ///
+    /// # Returns
+    /// A mutable reference to the value, borrowing the map.
+    #[inline]
+    pub fn into_mut(self) -> &'m mut V {
+        // NOTE: Can't fail, as `self.idx` is in-bounds for as long as we borrow the map
+        &mut self.map.vec.as_slice_mut()[self.idx].1
+    }
+
+    /// Replaces the value of this entry.
+    ///
+    /// # Arguments
+    /// - `value`: The new value.
+    ///
+    /// # Returns
+    /// The old value.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V { std::mem::replace(self.get_mut(), value) }
+
+    /// Removes this entry from the map.
+    ///
+    /// # Returns
+    /// The key and value of the removed entry.
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        // NOTE: Can't fail, as `self.idx` is in-bounds for as long as we borrow the map
+        self.map.vec.remove(self.idx).unwrap_or_else(|| unreachable!())
+    }
+
+    /// Removes this entry from the map.
+    ///
+    /// # Returns
+    /// The value of the removed entry.
+    #[inline]
+    pub fn remove(self) -> V { self.remove_entry().1 }
+}
+
+/// A view into an entry of a [`StackMap`] whose key is not in it yet.
+pub struct VacantMapEntry<'m, const LEN: usize, K, V> {
+    /// The map that we're an entry of.
+    map: &'m mut StackMap<LEN, K, V>,
+    /// The key that was looked up.
+    key: K,
+}
+impl<'m, const LEN: usize, K, V> VacantMapEntry<'m, LEN, K, V> {
+    /// Returns the key of this entry.
+    ///
+    /// # Returns
+    /// A reference to the key that was looked up.
+    #[inline]
+    pub fn key(&self) -> &K { &self.key }
+
+    /// Returns the key of this entry, without inserting anything.
+    ///
+    /// # Returns
+    /// The key that was looked up.
+    #[inline]
+    pub fn into_key(self) -> K { self.key }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a value for this entry's key.
+    ///
+    /// # Arguments
+    /// - `value`: The value to insert.
+    ///
+    /// # Returns
+    /// A mutable reference to the inserted value.
+    ///
+    /// # Panics
+    /// This function panics if the map is full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(self, value: V) -> &'m mut V {
+        match self.try_insert(value) {
+            Ok(value) => value,
+            Err(_) => panic!("Cannot insert entry into full StackMap of capacity {LEN}"),
+        }
+    }
+
+    /// Inserts a value for this entry's key, returning an error if the map is full.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](VacantMapEntry::insert()).
+    ///
+    /// # Arguments
+    /// - `value`: The value to insert.
+    ///
+    /// # Returns
+    /// A mutable reference to the inserted value.
+    ///
+    /// # Errors
+    /// If the map is already full, a [`CapacityError`] is returned that carries the key and the given `value`. The map is untouched in that case.
+    #[inline]
+    pub fn try_insert(self, value: V) -> Result<&'m mut V, CapacityError<(K, V)>> {
+        self.map.vec.try_push((self.key, value))?;
+        // NOTE: Can't fail, as we just pushed it
+        Ok(&mut self.map.vec.as_slice_mut().last_mut().unwrap_or_else(|| unreachable!()).1)
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a small, fixed-capacity map that lives in the stack.
+///
+/// Entries are stored in insertion order, and looked up by scanning them linearly. That makes it only suitable for small maps (say, up to a few dozen entries), but it doesn't require `K` to be [`Hash`] or [`Ord`] and never allocates.
+#[derive(Clone)]
+pub struct StackMap<const LEN: usize, K, V> {
+    /// The entries of the map.
+    ///
+    /// We implement the StackMap such that it upholds the following assertion: no two entries in `vec` have the same key.
+    vec: StackVec<LEN, (K, V)>,
+}
+impl<const LEN: usize, K, V> Default for StackMap<LEN, K, V> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, K, V> StackMap<LEN, K, V> {
+    /// Constructor for the StackMap that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackMap with no entries in it.
+    #[inline]
+    pub const fn new() -> Self { Self { vec: StackVec::new() } }
+
+    /// Removes all entries from the StackMap.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Returns an iterator over the entries in the StackMap, in insertion order.
+    ///
+    /// # Returns
+    /// A [`MapIter`] yielding references to the keys and values.
+    #[inline]
+    pub fn iter(&self) -> MapIter<'_, K, V> { MapIter { iter: self.vec.iter() } }
+
+    /// Returns an iterator over the entries in the StackMap, in insertion order, with mutable access to the values.
+    ///
+    /// # Returns
+    /// A [`MapIterMut`] yielding references to the keys and mutable references to the values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> MapIterMut<'_, K, V> { MapIterMut { iter: self.vec.iter_mut() } }
+
+    /// Returns an iterator over the keys in the StackMap, in insertion order.
+    ///
+    /// # Returns
+    /// An iterator yielding references to the keys.
+    #[inline]
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator + FusedIterator { self.vec.iter().map(|(key, _)| key) }
+
+    /// Returns an iterator over the values in the StackMap, in insertion order.
+    ///
+    /// # Returns
+    /// An iterator yielding references to the values.
+    #[inline]
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator + FusedIterator { self.vec.iter().map(|(_, value)| value) }
+
+    /// Returns an iterator over the values in the StackMap, in insertion order, mutably.
+    ///
+    /// # Returns
+    /// An iterator yielding mutable references to the values.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator + FusedIterator {
+        self.vec.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Returns the entries of the StackMap as a StackVec of key/value pairs.
+    ///
+    /// # Returns
+    /// The StackVec that backs this StackMap, in insertion order.
+    #[inline]
+    pub fn into_stackvec(self) -> StackVec<LEN, (K, V)> { self.vec }
+
+    /// Returns the number of entries in the StackMap.
+    ///
+    /// # Returns
+    /// The number of entries currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() }
+
+    /// Returns whether there are any entries in the StackMap.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.vec.is_empty() }
+
+    /// Returns whether the StackMap has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more entries fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.vec.is_full() }
+
+    /// Returns the maximum number of entries the StackMap can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<const LEN: usize, K: Eq, V> StackMap<LEN, K, V> {
+    /// Finds the index of the entry with the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look for.
+    ///
+    /// # Returns
+    /// The index of the entry in `self.vec`, or [`None`] if there is none.
+    #[inline]
+    fn position<Q: ?Sized + Eq>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.vec.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a key/value pair into the StackMap.
+    ///
+    /// If the key is already in the map, its value is replaced and the key is left as-is. Otherwise, the pair is added at the end.
+    ///
+    /// # Arguments
+    /// - `key`: The key to insert.
+    /// - `value`: The value to associate with it.
+    ///
+    /// # Returns
+    /// The old value associated with `key`, or [`None`] if there was none.
+    ///
+    /// # Panics
+    /// This function panics if `key` is not in the map yet, and the map is full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("Cannot insert entry into full StackMap of capacity {LEN}"),
+        }
+    }
+
+    /// Inserts a key/value pair into the StackMap, returning an error if it is full.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](StackMap::insert()). Replacing the value of an existing key always succeeds.
+    ///
+    /// # Arguments
+    /// - `key`: The key to insert.
+    /// - `value`: The value to associate with it.
+    ///
+    /// # Returns
+    /// The old value associated with `key`, or [`None`] if there was none.
+    ///
+    /// # Errors
+    /// If `key` is not in the map yet and the map is full, a [`CapacityError`] is returned that carries the given `key` and `value`. The map is untouched in that case.
+    #[inline]
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.entry(key) {
+            MapEntry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            MapEntry::Vacant(entry) => entry.try_insert(value).map(|_| None),
+        }
+    }
+
+    /// Returns the entry for the given key, to inspect or modify it in-place.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up.
+    ///
+    /// # Returns
+    /// A [`MapEntry`] that is either occupied (if `key` is in the map) or vacant.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> MapEntry<'_, LEN, K, V> {
+        match self.position(&key) {
+            Some(idx) => MapEntry::Occupied(OccupiedMapEntry { map: self, idx }),
+            None => MapEntry::Vacant(VacantMapEntry { map: self, key }),
+        }
+    }
+
+    /// Returns the value associated with the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// A reference to the value, or [`None`] if `key` is not in the map.
+    #[inline]
+    pub fn get<Q: ?Sized + Eq>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the key and value associated with the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// A reference to the stored key and value, or [`None`] if `key` is not in the map.
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Eq>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.vec.iter().find(|(k, _)| k.borrow() == key).map(|(key, value)| (key, value))
+    }
+
+    /// Returns the value associated with the given key, mutably.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// A mutable reference to the value, or [`None`] if `key` is not in the map.
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.vec.iter_mut().find(|(k, _)| (*k).borrow() == key).map(|(_, value)| value)
+    }
+
+    /// Returns whether the given key is in the StackMap.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// True if it is, false otherwise.
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Eq>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Removes the entry with the given key from the StackMap.
+    ///
+    /// This preserves the insertion order of the other entries.
+    ///
+    /// # Arguments
+    /// - `key`: The key to remove. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// The stored key and value, or [`None`] if `key` was not in the map.
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let idx: usize = self.position(key)?;
+        self.vec.remove(idx)
+    }
+
+    /// Removes the entry with the given key from the StackMap.
+    ///
+    /// This preserves the insertion order of the other entries.
+    ///
+    /// # Arguments
+    /// - `key`: The key to remove. May be any borrowed form of `K`.
+    ///
+    /// # Returns
+    /// The value that was associated with `key`, or [`None`] if it was not in the map.
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Keeps only the entries for which the given predicate returns true.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that decides whether to keep an entry.
+    #[inline]
+    pub fn retain(&mut self, mut pred: impl FnMut(&K, &mut V) -> bool) { self.vec.extract_if(|(key, value)| !pred(key, value)).for_each(drop) }
+}
+
+impl<const LEN: usize, K: Debug, V: Debug> Debug for StackMap<LEN, K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_map().entries(self.iter()).finish() }
+}
+impl<const LEN: usize, K: Eq, V: Eq> Eq for StackMap<LEN, K, V> {}
+impl<const LEN: usize, const LEN2: usize, K: Eq, V: PartialEq> PartialEq<StackMap<LEN2, K, V>> for StackMap<LEN, K, V> {
+    /// Compares the maps regardless of insertion order.
+    #[inline]
+    fn eq(&self, other: &StackMap<LEN2, K, V>) -> bool { self.len() == other.len() && self.iter().all(|(key, value)| other.get(key) == Some(value)) }
+}
+
+// Indexing
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, K: Eq + Borrow<Q>, Q: ?Sized + Eq, V> Index<&Q> for StackMap<LEN, K, V> {
+    type Output = V;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: &Q) -> &Self::Output {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("Key not found in StackMap"),
+        }
+    }
+}
+
+// Iteration
+impl<const LEN: usize, K, V> IntoIterator for StackMap<LEN, K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<LEN, (K, V)>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
+}
+impl<'m, const LEN: usize, K, V> IntoIterator for &'m StackMap<LEN, K, V> {
+    type Item = (&'m K, &'m V);
+    type IntoIter = MapIter<'m, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl<'m, const LEN: usize, K, V> IntoIterator for &'m mut StackMap<LEN, K, V> {
+    type Item = (&'m K, &'m mut V);
+    type IntoIter = MapIterMut<'m, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+// Conversion
+impl<const LEN: usize, const LEN2: usize, K: Eq, V> TryFrom<[(K, V); LEN2]> for StackMap<LEN, K, V> {
+    type Error = CapacityError;
+
+    /// Collects the pairs into a map, where later values replace earlier ones for the same key.
+    #[inline]
+    fn try_from(value: [(K, V); LEN2]) -> Result<Self, Self::Error> {
+        let mut map: Self = Self::new();
+        let mut iter = value.into_iter();
+        while let Some((key, value)) = iter.next() {
+            if map.try_insert(key, value).is_err() {
+                // Count the one that failed, too
+                return Err(CapacityError::with_excess((), LEN, 1 + iter.len()));
+            }
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,212 @@
+//  Description:
+//!   Implements a [`StackMatrix`], a fixed-size 2D grid that lives in the
+//!   stack.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+#[cfg(not(feature = "no-panic"))]
+use std::ops::{Index, IndexMut};
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-size, row-major matrix of `R` rows by `C` columns that lives in the stack.
+///
+/// Unlike the [`StackVec`](crate::StackVec), all `R * C` elements are always initialized. This makes it a good fit for small grids (e.g., game boards or convolution kernels) that must not allocate. As it doesn't need [`Drop`], it is [`Copy`] if `T` is.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct StackMatrix<const R: usize, const C: usize, T> {
+    /// The rows of the matrix.
+    data: [[T; C]; R],
+}
+impl<const R: usize, const C: usize, T: Default> Default for StackMatrix<R, C, T> {
+    #[inline]
+    fn default() -> Self { Self::from_fn(|_, _| T::default()) }
+}
+impl<const R: usize, const C: usize, T> StackMatrix<R, C, T> {
+    /// Constructor for the StackMatrix that takes over the given rows.
+    ///
+    /// # Arguments
+    /// - `rows`: The `R` rows of `C` elements each.
+    ///
+    /// # Returns
+    /// A new StackMatrix with the given elements.
+    #[inline]
+    pub const fn from_rows(rows: [[T; C]; R]) -> Self { Self { data: rows } }
+
+    /// Constructor for the StackMatrix that generates every element.
+    ///
+    /// # Arguments
+    /// - `f`: A closure that is called with the row and column of every element (in row-major order) to generate it.
+    ///
+    /// # Returns
+    /// A new StackMatrix with the generated elements.
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self { Self { data: std::array::from_fn(|row| std::array::from_fn(|col| f(row, col))) } }
+
+    /// Returns the rows of the StackMatrix.
+    ///
+    /// # Returns
+    /// The `R` rows of `C` elements each.
+    #[inline]
+    pub fn into_rows(self) -> [[T; C]; R] { self.data }
+
+    /// Returns the element at the given position.
+    ///
+    /// # Arguments
+    /// - `row`: The row of the element.
+    /// - `col`: The column of the element.
+    ///
+    /// # Returns
+    /// A reference to the element, or [`None`] if the position is out-of-bounds.
+    #[inline]
+    pub const fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < R && col < C { Some(&self.data[row][col]) } else { None }
+    }
+
+    /// Returns the element at the given position, mutably.
+    ///
+    /// # Arguments
+    /// - `row`: The row of the element.
+    /// - `col`: The column of the element.
+    ///
+    /// # Returns
+    /// A mutable reference to the element, or [`None`] if the position is out-of-bounds.
+    #[inline]
+    pub const fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < R && col < C { Some(&mut self.data[row][col]) } else { None }
+    }
+
+    /// Returns a single row of the StackMatrix.
+    ///
+    /// # Arguments
+    /// - `row`: The index of the row.
+    ///
+    /// # Returns
+    /// A reference to the `C` elements in the row, or [`None`] if it is out-of-bounds.
+    #[inline]
+    pub const fn row(&self, row: usize) -> Option<&[T; C]> { if row < R { Some(&self.data[row]) } else { None } }
+
+    /// Returns a single row of the StackMatrix, mutably.
+    ///
+    /// # Arguments
+    /// - `row`: The index of the row.
+    ///
+    /// # Returns
+    /// A mutable reference to the `C` elements in the row, or [`None`] if it is out-of-bounds.
+    #[inline]
+    pub const fn row_mut(&mut self, row: usize) -> Option<&mut [T; C]> { if row < R { Some(&mut self.data[row]) } else { None } }
+
+    /// Returns an iterator over the rows of the StackMatrix.
+    ///
+    /// # Returns
+    /// An iterator yielding a reference to each row, from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> std::slice::Iter<'_, [T; C]> { self.data.iter() }
+
+    /// Returns an iterator over the rows of the StackMatrix, mutably.
+    ///
+    /// # Returns
+    /// An iterator yielding a mutable reference to each row, from top to bottom.
+    #[inline]
+    pub fn rows_mut(&mut self) -> std::slice::IterMut<'_, [T; C]> { self.data.iter_mut() }
+
+    /// Returns an iterator over a single column of the StackMatrix.
+    ///
+    /// # Arguments
+    /// - `col`: The index of the column.
+    ///
+    /// # Returns
+    /// An iterator yielding a reference to each element in the column, from top to bottom. It yields nothing if `col` is out-of-bounds.
+    #[inline]
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        // NOTE: Skip all rows if the column is out-of-bounds, so indexing them can't fail
+        let rows: &[[T; C]] = if col < C { &self.data } else { &[] };
+        rows.iter().map(move |row| &row[col])
+    }
+
+    /// Returns all elements of the StackMatrix, in row-major order.
+    ///
+    /// # Returns
+    /// A slice of `R * C` elements.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] { self.data.as_flattened() }
+
+    /// Returns all elements of the StackMatrix, in row-major order, mutably.
+    ///
+    /// # Returns
+    /// A mutable slice of `R * C` elements.
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] { self.data.as_flattened_mut() }
+
+    /// Returns the number of rows in the StackMatrix.
+    ///
+    /// # Returns
+    /// `R`.
+    #[inline]
+    pub const fn num_rows(&self) -> usize { R }
+
+    /// Returns the number of columns in the StackMatrix.
+    ///
+    /// # Returns
+    /// `C`.
+    #[inline]
+    pub const fn num_columns(&self) -> usize { C }
+}
+impl<const R: usize, const C: usize, T: Clone> StackMatrix<R, C, T> {
+    /// Constructor for the StackMatrix that fills it with clones of the given value.
+    ///
+    /// # Arguments
+    /// - `value`: The value to put at every position.
+    ///
+    /// # Returns
+    /// A new StackMatrix with `R * C` clones of `value`.
+    #[inline]
+    pub fn filled(value: T) -> Self { Self::from_fn(|_, _| value.clone()) }
+
+    /// Returns the transpose of the StackMatrix, i.e., with its rows and columns swapped.
+    ///
+    /// # Returns
+    /// A new StackMatrix of `C` rows by `R` columns, where the element at `(row, col)` is the one at `(col, row)` in this one.
+    #[inline]
+    pub fn transpose(&self) -> StackMatrix<C, R, T> { StackMatrix::from_fn(|row, col| self.data[col][row].clone()) }
+}
+
+impl<const R: usize, const C: usize, T: Debug> Debug for StackMatrix<R, C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_list().entries(self.rows()).finish() }
+}
+
+// Indexing
+#[cfg(not(feature = "no-panic"))]
+impl<const R: usize, const C: usize, T> Index<(usize, usize)> for StackMatrix<R, C, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(elem) => elem,
+            None => panic!("Position ({row}, {col}) is out-of-bounds for StackMatrix of size {R}x{C}"),
+        }
+    }
+}
+#[cfg(not(feature = "no-panic"))]
+impl<const R: usize, const C: usize, T> IndexMut<(usize, usize)> for StackMatrix<R, C, T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        match self.get_mut(row, col) {
+            Some(elem) => elem,
+            None => panic!("Position ({row}, {col}) is out-of-bounds for StackMatrix of size {R}x{C}"),
+        }
+    }
+}
+
+// Conversion
+impl<const R: usize, const C: usize, T> From<[[T; C]; R]> for StackMatrix<R, C, T> {
+    #[inline]
+    fn from(value: [[T; C]; R]) -> Self { Self::from_rows(value) }
+}
+impl<const R: usize, const C: usize, T> From<StackMatrix<R, C, T>> for [[T; C]; R] {
+    #[inline]
+    fn from(value: StackMatrix<R, C, T>) -> Self { value.data }
+}
@@ -0,0 +1,40 @@
+//  Description:
+//!   Provides [`proptest`] strategies for generating [`StackVec`]s,
+//!   available under the `proptest` feature.
+//
+
+use proptest::collection::{SizeRange, vec};
+use proptest::strategy::Strategy;
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+/// Creates a strategy that generates StackVecs with elements from the given strategy.
+///
+/// Generated StackVecs shrink by first removing elements, and then by shrinking the remaining elements individually.
+///
+/// # Arguments
+/// - `element`: The [`Strategy`] that generates the individual elements.
+/// - `size`: The range of lengths of the generated StackVecs. Any part of it that exceeds `LEN` is clamped to `LEN`.
+///
+/// # Returns
+/// A [`Strategy`] generating StackVecs with a length in `size`.
+///
+/// # Example
+/// ```ignore
+/// use proptest::prelude::*;
+/// use stackvec::StackVec;
+///
+/// proptest! {
+///     #[test]
+///     fn never_exceeds_capacity(vec in stackvec::proptest::stack_vec::<5, _, _>(any::<u8>(), 0..=5)) {
+///         prop_assert!(vec.len() <= 5);
+///     }
+/// }
+/// ```
+#[inline]
+pub fn stack_vec<const LEN: usize, S: Strategy, P>(element: S, size: impl Into<SizeRange>) -> impl Strategy<Value = StackVec<LEN, S::Value, P>> {
+    let (start, end): (usize, usize) = size.into().start_end_incl();
+    vec(element, start.min(LEN)..=end.min(LEN)).prop_map(StackVec::from_iter_truncated)
+}
@@ -0,0 +1,25 @@
+//  Description:
+//!   Implements [`quickcheck`]'s [`Arbitrary`] for the [`StackVec`],
+//!   available under the `quickcheck` feature.
+//
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T: Arbitrary, P: 'static> Arbitrary for StackVec<LEN, T, P> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        // Like `Vec`, let the generator's size bound the length (as well as our capacity)
+        let len: usize = usize::arbitrary(g) % (LEN.min(g.size()) + 1);
+        Self::from_iter_truncated((0..len).map(|_| T::arbitrary(g)))
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrinking never makes it longer, so we can rely on `Vec`'s strategy of shrinking the length first
+        Box::new(self.to_vec().shrink().map(Self::from_iter_truncated))
+    }
+}
@@ -0,0 +1,123 @@
+//  Description:
+//!   Implements helpers for generating and sampling [`StackVec`]s using
+//!   [`rand`], available under the `rand` feature.
+//
+
+use std::mem::MaybeUninit;
+
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use rand::seq::{IndexedMutRandom as _, IndexedRandom as _, SliceRandom as _};
+
+use crate::{CapacityError, StackVec};
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T, P> StackVec<LEN, T, P>
+where
+    StandardUniform: Distribution<T>,
+{
+    #[cfg(not(feature = "no-panic"))]
+    /// Constructor for the StackVec that fills it with random elements.
+    ///
+    /// # Arguments
+    /// - `rng`: The [`Rng`] to generate the elements with.
+    /// - `len`: The number of elements to generate.
+    ///
+    /// # Returns
+    /// A new StackVec with `len` elements, sampled from the [`StandardUniform`] distribution.
+    ///
+    /// # Panics
+    /// This function panics if `len` exceeds the capacity of the StackVec.
+    #[inline]
+    #[track_caller]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, len: usize) -> Self {
+        match Self::try_random(rng, len) {
+            Ok(stack) => stack,
+            Err(_) => panic!("Cannot generate {len} elements in StackVec of capacity {LEN}"),
+        }
+    }
+
+    /// Constructor for the StackVec that fills it with random elements, returning an error if they would not fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::random()`](StackVec::random()).
+    ///
+    /// # Arguments
+    /// - `rng`: The [`Rng`] to generate the elements with.
+    /// - `len`: The number of elements to generate.
+    ///
+    /// # Returns
+    /// A new StackVec with `len` elements, sampled from the [`StandardUniform`] distribution.
+    ///
+    /// # Errors
+    /// If `len` exceeds the capacity of the StackVec, a [`CapacityError`] is returned that carries the number of elements that would not have fit. No elements are generated in that case.
+    #[inline]
+    pub fn try_random<R: Rng + ?Sized>(rng: &mut R, len: usize) -> Result<Self, CapacityError> {
+        if len > LEN {
+            return Err(CapacityError::with_excess((), LEN, len - LEN));
+        }
+
+        let mut stack: Self = Self::new();
+        for _ in 0..len {
+            // NOTE: Can't fail, as we checked that `len` fits
+            stack.data[stack.len] = MaybeUninit::new(rng.random());
+            stack.len += 1;
+        }
+        Ok(stack)
+    }
+}
+
+impl<const LEN: usize, T, P> StackVec<LEN, T, P> {
+    /// Shuffles the elements in the StackVec in-place.
+    ///
+    /// This is a shorthand for [`SliceRandom::shuffle()`](rand::seq::SliceRandom::shuffle()) on the StackVec's elements.
+    ///
+    /// # Arguments
+    /// - `rng`: The [`Rng`] that decides the new order.
+    #[inline]
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) { self.as_slice_mut().shuffle(rng) }
+
+    /// Returns a uniformly random element in the StackVec.
+    ///
+    /// This is a shorthand for [`IndexedRandom::choose()`](rand::seq::IndexedRandom::choose()) on the StackVec's elements.
+    ///
+    /// # Arguments
+    /// - `rng`: The [`Rng`] that picks the element.
+    ///
+    /// # Returns
+    /// A reference to the chosen element, or [`None`] if the StackVec is empty.
+    #[inline]
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> { self.as_slice().choose(rng) }
+
+    /// Returns a uniformly random element in the StackVec, mutably.
+    ///
+    /// This is a shorthand for [`IndexedMutRandom::choose_mut()`](rand::seq::IndexedMutRandom::choose_mut()) on the StackVec's elements.
+    ///
+    /// # Arguments
+    /// - `rng`: The [`Rng`] that picks the element.
+    ///
+    /// # Returns
+    /// A mutable reference to the chosen element, or [`None`] if the StackVec is empty.
+    #[inline]
+    pub fn choose_mut<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&mut T> { self.as_slice_mut().choose_mut(rng) }
+}
+
+// Sampling
+/// Samples full StackVecs, i.e., of `LEN` elements each sampled from the [`StandardUniform`] distribution.
+///
+/// This mirrors how arrays are sampled. Use [`StackVec::random()`] to generate a StackVec with fewer elements.
+impl<const LEN: usize, T, P> Distribution<StackVec<LEN, T, P>> for StandardUniform
+where
+    StandardUniform: Distribution<T>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> StackVec<LEN, T, P> {
+        let mut stack: StackVec<LEN, T, P> = StackVec::new();
+        for _ in 0..LEN {
+            // NOTE: Can't fail, as we generate exactly `LEN` elements
+            stack.data[stack.len] = MaybeUninit::new(rng.random());
+            stack.len += 1;
+        }
+        stack
+    }
+}
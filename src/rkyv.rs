@@ -0,0 +1,149 @@
+//  Description:
+//!   Implements [`rkyv`]'s [`Archive`], [`Serialize`] and [`Deserialize`]
+//!   for the [`StackVec`], available under the `rkyv` feature.
+//!
+//!   StackVecs are archived inline as an [`ArchivedStackVec`], which has the
+//!   same fixed capacity as the original StackVec.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::munge::munge;
+use rkyv::primitive::ArchivedUsize;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::{CapacityError, StackVec};
+
+
+/***** LIBRARY *****/
+/// The archived counterpart of a [`StackVec`].
+///
+/// Like the StackVec itself, it stores all of its (archived) elements inline in a buffer of `LEN` elements.
+#[repr(C)]
+pub struct ArchivedStackVec<const LEN: usize, T> {
+    /// The number of elements in `data` that are initialized.
+    len:  ArchivedUsize,
+    /// The archived elements, of which the first `len` are initialized.
+    data: [MaybeUninit<T>; LEN],
+}
+impl<const LEN: usize, T> ArchivedStackVec<LEN, T> {
+    /// Returns the number of elements in this ArchivedStackVec.
+    ///
+    /// # Returns
+    /// The number of archived elements.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len.to_native() as usize }
+
+    /// Returns whether there are any elements in this ArchivedStackVec.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the maximum number of elements this ArchivedStackVec can hold.
+    ///
+    /// # Returns
+    /// The `LEN` of the original StackVec.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+
+    /// Returns this ArchivedStackVec as a slice of archived elements.
+    ///
+    /// # Returns
+    /// A [`&[T]`] that has the length of this ArchivedStackVec.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `self.len` elements are initialized, either because we wrote them in `StackVec::resolve()` or because they were checked by `ArchivedStackVec::check_bytes()`.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr() as *const T, self.len()) }
+    }
+}
+
+// SAFETY: The ArchivedStackVec is `repr(C)` and only consists of portable types, as long as `T` is also portable.
+unsafe impl<const LEN: usize, T: Portable> Portable for ArchivedStackVec<LEN, T> {}
+// SAFETY: We check that the length is within the capacity and then check only the elements that are initialized.
+unsafe impl<const LEN: usize, T: CheckBytes<C>, C: Fallible + ?Sized> CheckBytes<C> for ArchivedStackVec<LEN, T>
+where
+    C::Error: Source,
+{
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        // SAFETY: The caller promises that `value` points to enough bytes to represent an ArchivedStackVec, so also to represent its length.
+        let len: *const ArchivedUsize = unsafe { &raw const (*value).len };
+        // SAFETY: See above.
+        unsafe { ArchivedUsize::check_bytes(len, context)? };
+        // SAFETY: We just checked the length is valid.
+        let len: usize = unsafe { (*len).to_native() } as usize;
+        if len > LEN {
+            return Err(C::Error::new(CapacityError::with_excess((), LEN, len - LEN)));
+        }
+
+        // Check the initialized elements
+        // SAFETY: The caller promises that `value` points to enough bytes to represent an ArchivedStackVec, so also to represent its elements.
+        let data: *const T = unsafe { &raw const (*value).data } as *const T;
+        for i in 0..len {
+            // SAFETY: `i` is within `LEN`, so the pointer is within the `data` array.
+            unsafe { T::check_bytes(data.add(i), context)? };
+        }
+        Ok(())
+    }
+}
+
+impl<const LEN: usize, T> Deref for ArchivedStackVec<LEN, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+impl<const LEN: usize, T: Debug> Debug for ArchivedStackVec<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_list().entries(self.as_slice()).finish() }
+}
+
+// Archiving
+impl<const LEN: usize, T: Archive, P> Archive for StackVec<LEN, T, P> {
+    type Archived = ArchivedStackVec<LEN, T::Archived>;
+    type Resolver = StackVec<LEN, T::Resolver>;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedStackVec { len, data } = out);
+        self.len.resolve((), len);
+        for (i, (elem, resolver)) in self.iter().zip(resolver).enumerate() {
+            // SAFETY: `i` is within `self.len`, and therefore within `LEN`. Further, `MaybeUninit<T::Archived>` has the same layout as `T::Archived`.
+            let out: Place<T::Archived> = unsafe { data.index(i).cast_unchecked() };
+            elem.resolve(resolver, out);
+        }
+    }
+}
+impl<const LEN: usize, T: Serialize<S>, S: Fallible + ?Sized, P> Serialize<S> for StackVec<LEN, T, P> {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let mut resolvers: StackVec<LEN, T::Resolver> = StackVec::new();
+        for elem in self {
+            // NOTE: Can't fail, as we have as many resolvers as elements
+            resolvers.data[resolvers.len] = MaybeUninit::new(elem.serialize(serializer)?);
+            resolvers.len += 1;
+        }
+        Ok(resolvers)
+    }
+}
+impl<const LEN: usize, T: Archive, D: Fallible + ?Sized, P> Deserialize<StackVec<LEN, T, P>, D> for ArchivedStackVec<LEN, T::Archived>
+where
+    T::Archived: Deserialize<T, D>,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<StackVec<LEN, T, P>, D::Error> {
+        let mut stack: StackVec<LEN, T, P> = StackVec::new();
+        for elem in self.as_slice() {
+            // NOTE: Can't fail, as an ArchivedStackVec never has more than `LEN` elements
+            stack.data[stack.len] = MaybeUninit::new(elem.deserialize(deserializer)?);
+            stack.len += 1;
+        }
+        Ok(stack)
+    }
+}
@@ -0,0 +1,33 @@
+//  Description:
+//!   Implements [`schemars`]'s [`JsonSchema`] for the [`StackVec`],
+//!   available under the `schemars` feature.
+//
+
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+/// Describes StackVecs as arrays of at most `LEN` elements, matching how they are (de)serialized with the `serde` feature.
+impl<const LEN: usize, T: JsonSchema, P> JsonSchema for StackVec<LEN, T, P> {
+    #[inline]
+    fn inline_schema() -> bool { true }
+
+    #[inline]
+    fn schema_name() -> Cow<'static, str> { format!("StackVec_size_{}_of_{}", LEN, T::schema_name()).into() }
+
+    #[inline]
+    fn schema_id() -> Cow<'static, str> { format!("StackVec<{}; {}>", LEN, T::schema_id()).into() }
+
+    #[inline]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<T>(),
+            "maxItems": LEN,
+        })
+    }
+}
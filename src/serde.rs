@@ -0,0 +1,107 @@
+//  Description:
+//!   Implements [`serde`]'s [`Serialize`] and [`Deserialize`] for the
+//!   [`StackVec`], available under the `serde` feature.
+//!
+//!   Under the `serde_bytes` feature, also implements [`serde_bytes`]'s
+//!   traits for byte StackVecs, such that they can be (de)serialized as
+//!   byte strings using `#[serde(with = "serde_bytes")]`.
+//
+
+use std::fmt::{Formatter, Result as FResult};
+use std::marker::PhantomData;
+
+use serde::de::{Error as _, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::StackVec;
+
+
+/***** HELPERS *****/
+/// Visits a sequence of at most `LEN` elements.
+struct StackVecVisitor<const LEN: usize, T, P> {
+    /// Remembers the type we're deserializing.
+    _type: PhantomData<StackVec<LEN, T, P>>,
+}
+impl<'de, const LEN: usize, T: Deserialize<'de>, P> Visitor<'de> for StackVecVisitor<LEN, T, P> {
+    type Value = StackVec<LEN, T, P>;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "a sequence of at most {LEN} elements") }
+
+    #[inline]
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Fail early if the format already tells us it won't fit
+        if let Some(len) = seq.size_hint().filter(|len| *len > LEN) {
+            return Err(A::Error::invalid_length(len, &self));
+        }
+
+        let mut stack: StackVec<LEN, T, P> = StackVec::new();
+        while let Some(elem) = seq.next_element()? {
+            if stack.push_within_capacity(elem).is_err() {
+                // Count the remaining elements for a helpful error message
+                let mut len: usize = LEN + 1;
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+                return Err(A::Error::invalid_length(len, &self));
+            }
+        }
+        Ok(stack)
+    }
+}
+
+/// Visits a byte string of at most `LEN` bytes.
+#[cfg(feature = "serde_bytes")]
+struct StackVecBytesVisitor<const LEN: usize, P> {
+    /// Remembers the type we're deserializing.
+    _type: PhantomData<StackVec<LEN, u8, P>>,
+}
+#[cfg(feature = "serde_bytes")]
+impl<'de, const LEN: usize, P> Visitor<'de> for StackVecBytesVisitor<LEN, P> {
+    type Value = StackVec<LEN, u8, P>;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> FResult { write!(f, "a byte string of at most {LEN} bytes") }
+
+    #[inline]
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let mut stack: StackVec<LEN, u8, P> = StackVec::new();
+        stack.try_extend_from_copy_slice(v).map_err(|_| E::invalid_length(v.len(), &self))?;
+        Ok(stack)
+    }
+
+    #[inline]
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        // Formats without native byte strings (e.g., JSON) encode them as sequences
+        StackVecVisitor { _type: PhantomData }.visit_seq(seq)
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T: Serialize, P> Serialize for StackVec<LEN, T, P> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.collect_seq(self.iter()) }
+}
+impl<'de, const LEN: usize, T: Deserialize<'de>, P> Deserialize<'de> for StackVec<LEN, T, P> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(StackVecVisitor { _type: PhantomData })
+    }
+}
+
+#[cfg(feature = "serde_bytes")]
+impl<const LEN: usize, P> serde_bytes::Serialize for StackVec<LEN, u8, P> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { serializer.serialize_bytes(self.as_slice()) }
+}
+#[cfg(feature = "serde_bytes")]
+impl<'de, const LEN: usize, P> serde_bytes::Deserialize<'de> for StackVec<LEN, u8, P> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(StackVecBytesVisitor { _type: PhantomData })
+    }
+}
@@ -0,0 +1,320 @@
+//  Description:
+//!   Implements a [`StackSet`], a small fixed-capacity set that lives in the
+//!   stack.
+//
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as FResult};
+
+use crate::{CapacityError, IntoIter, StackVec};
+
+
+/***** LIBRARY *****/
+/// Implements a small, fixed-capacity set that lives in the stack.
+///
+/// This is the companion of the [`StackMap`](crate::StackMap): elements are stored in insertion order, and looked up by scanning them linearly. That makes it only suitable for small sets, but it doesn't require `T` to be [`Hash`] or [`Ord`] and never allocates.
+#[derive(Clone)]
+pub struct StackSet<const LEN: usize, T> {
+    /// The elements of the set.
+    ///
+    /// We implement the StackSet such that it upholds the following assertion: no two elements in `vec` are equal.
+    vec: StackVec<LEN, T>,
+}
+impl<const LEN: usize, T> Default for StackSet<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> StackSet<LEN, T> {
+    /// Constructor for the StackSet that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackSet with no elements in it.
+    #[inline]
+    pub const fn new() -> Self { Self { vec: StackVec::new() } }
+
+    /// Removes all elements from the StackSet.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Returns the elements in the StackSet, in insertion order.
+    ///
+    /// # Returns
+    /// A slice of all elements.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { self.vec.as_slice() }
+
+    /// Returns an iterator over the elements in the StackSet, in insertion order.
+    ///
+    /// # Returns
+    /// An iterator yielding references to the elements.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.vec.iter() }
+
+    /// Returns the elements of the StackSet as a StackVec.
+    ///
+    /// # Returns
+    /// The StackVec that backs this StackSet, in insertion order.
+    #[inline]
+    pub fn into_stackvec(self) -> StackVec<LEN, T> { self.vec }
+
+    /// Returns the number of elements in the StackSet.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() }
+
+    /// Returns whether there are any elements in the StackSet.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.vec.is_empty() }
+
+    /// Returns whether the StackSet has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.vec.is_full() }
+
+    /// Returns the maximum number of elements the StackSet can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<const LEN: usize, T: Eq> StackSet<LEN, T> {
+    /// Finds the index of the given element.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to look for.
+    ///
+    /// # Returns
+    /// The index of the element in `self.vec`, or [`None`] if it's not in there.
+    #[inline]
+    fn position<Q: ?Sized + Eq>(&self, elem: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+    {
+        self.vec.iter().position(|e| e.borrow() == elem)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Adds an element to the StackSet.
+    ///
+    /// If an equal element is already in the set, it is left as-is and `elem` is dropped.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to add.
+    ///
+    /// # Returns
+    /// True if the element was new, or false if it was already in the set.
+    ///
+    /// # Panics
+    /// This function panics if `elem` is new, and the set is full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, elem: T) -> bool {
+        match self.try_insert(elem) {
+            Ok(new) => new,
+            Err(_) => panic!("Cannot insert element into full StackSet of capacity {LEN}"),
+        }
+    }
+
+    /// Adds an element to the StackSet, returning an error if it is full.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](StackSet::insert()). Inserting an element that is already in the set always succeeds.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to add.
+    ///
+    /// # Returns
+    /// True if the element was new, or false if it was already in the set.
+    ///
+    /// # Errors
+    /// If `elem` is new and the set is full, a [`CapacityError`] is returned that carries the given `elem`. The set is untouched in that case.
+    #[inline]
+    pub fn try_insert(&mut self, elem: T) -> Result<bool, CapacityError<T>> {
+        if self.contains(&elem) {
+            return Ok(false);
+        }
+        self.vec.try_push(elem)?;
+        Ok(true)
+    }
+
+    /// Returns whether the given element is in the StackSet.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to look for. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// True if it is, false otherwise.
+    #[inline]
+    pub fn contains<Q: ?Sized + Eq>(&self, elem: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.position(elem).is_some()
+    }
+
+    /// Returns the element in the StackSet that is equal to the given one.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to look for. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// A reference to the stored element, or [`None`] if it is not in the set.
+    #[inline]
+    pub fn get<Q: ?Sized + Eq>(&self, elem: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+    {
+        self.vec.iter().find(|e| (*e).borrow() == elem)
+    }
+
+    /// Removes the element equal to the given one from the StackSet, and returns it.
+    ///
+    /// This preserves the insertion order of the other elements.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to remove. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// The stored element, or [`None`] if it was not in the set.
+    #[inline]
+    pub fn take<Q: ?Sized + Eq>(&mut self, elem: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        let idx: usize = self.position(elem)?;
+        self.vec.remove(idx)
+    }
+
+    /// Removes the element equal to the given one from the StackSet.
+    ///
+    /// This preserves the insertion order of the other elements.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to remove. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// True if the element was in the set, or false otherwise.
+    #[inline]
+    pub fn remove<Q: ?Sized + Eq>(&mut self, elem: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.take(elem).is_some()
+    }
+
+    /// Keeps only the elements for which the given predicate returns true.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that decides whether to keep an element.
+    #[inline]
+    pub fn retain(&mut self, mut pred: impl FnMut(&T) -> bool) { self.vec.extract_if(|elem| !pred(elem)).for_each(drop) }
+
+    /// Returns the elements that are in this StackSet, in the other, or in both.
+    ///
+    /// # Arguments
+    /// - `other`: The other StackSet to take the union with.
+    ///
+    /// # Returns
+    /// An iterator yielding the elements of `self`, followed by those of `other` that are not in `self`.
+    #[inline]
+    pub fn union<'s, const LEN2: usize>(&'s self, other: &'s StackSet<LEN2, T>) -> impl Iterator<Item = &'s T> {
+        self.iter().chain(other.difference(self))
+    }
+
+    /// Returns the elements that are in both this StackSet and the other.
+    ///
+    /// # Arguments
+    /// - `other`: The other StackSet to take the intersection with.
+    ///
+    /// # Returns
+    /// An iterator yielding the elements of `self` that are also in `other`.
+    #[inline]
+    pub fn intersection<'s, const LEN2: usize>(&'s self, other: &'s StackSet<LEN2, T>) -> impl Iterator<Item = &'s T> {
+        self.iter().filter(|elem| other.contains(*elem))
+    }
+
+    /// Returns the elements that are in this StackSet, but not in the other.
+    ///
+    /// # Arguments
+    /// - `other`: The other StackSet to subtract.
+    ///
+    /// # Returns
+    /// An iterator yielding the elements of `self` that are not in `other`.
+    #[inline]
+    pub fn difference<'s, const LEN2: usize>(&'s self, other: &'s StackSet<LEN2, T>) -> impl Iterator<Item = &'s T> {
+        self.iter().filter(|elem| !other.contains(*elem))
+    }
+
+    /// Returns whether all elements of this StackSet are also in the other.
+    ///
+    /// # Arguments
+    /// - `other`: The other StackSet to compare with.
+    ///
+    /// # Returns
+    /// True if `self` is a subset of `other`, false otherwise.
+    #[inline]
+    pub fn is_subset<const LEN2: usize>(&self, other: &StackSet<LEN2, T>) -> bool { self.iter().all(|elem| other.contains(elem)) }
+
+    /// Returns whether this StackSet and the other have no elements in common.
+    ///
+    /// # Arguments
+    /// - `other`: The other StackSet to compare with.
+    ///
+    /// # Returns
+    /// True if the intersection of `self` and `other` is empty, false otherwise.
+    #[inline]
+    pub fn is_disjoint<const LEN2: usize>(&self, other: &StackSet<LEN2, T>) -> bool { self.intersection(other).next().is_none() }
+}
+
+impl<const LEN: usize, T: Debug> Debug for StackSet<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_set().entries(self.iter()).finish() }
+}
+impl<const LEN: usize, T: Eq> Eq for StackSet<LEN, T> {}
+impl<const LEN: usize, const LEN2: usize, T: Eq> PartialEq<StackSet<LEN2, T>> for StackSet<LEN, T> {
+    /// Compares the sets regardless of insertion order.
+    #[inline]
+    fn eq(&self, other: &StackSet<LEN2, T>) -> bool { self.len() == other.len() && self.is_subset(other) }
+}
+
+// Iteration
+impl<const LEN: usize, T> IntoIterator for StackSet<LEN, T> {
+    type Item = T;
+    type IntoIter = IntoIter<LEN, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
+}
+impl<'s, const LEN: usize, T> IntoIterator for &'s StackSet<LEN, T> {
+    type Item = &'s T;
+    type IntoIter = std::slice::Iter<'s, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+// Conversion
+impl<const LEN: usize, const LEN2: usize, T: Eq> TryFrom<[T; LEN2]> for StackSet<LEN, T> {
+    type Error = CapacityError;
+
+    /// Collects the elements into a set, dropping any duplicates.
+    #[inline]
+    fn try_from(value: [T; LEN2]) -> Result<Self, Self::Error> {
+        let mut set: Self = Self::new();
+        let mut iter = value.into_iter();
+        while let Some(elem) = iter.next() {
+            if set.try_insert(elem).is_err() {
+                // Count the one that failed, too
+                return Err(CapacityError::with_excess((), LEN, 1 + iter.len()));
+            }
+        }
+        Ok(set)
+    }
+}
@@ -0,0 +1,289 @@
+//  Description:
+//!   Implements a [`StackSlab`], a fixed-capacity slab with stable keys that
+//!   lives in the stack.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+#[cfg(not(feature = "no-panic"))]
+use std::ops::{Index, IndexMut};
+
+use crate::{CapacityError, StackVec};
+
+
+/***** HELPERS *****/
+/// Defines a single slot in a [`StackSlab`].
+#[derive(Clone)]
+enum Entry<T> {
+    /// The slot holds an element.
+    Occupied(T),
+    /// The slot is free, and links to the next free slot (or to the end of the used slots if there is none).
+    Vacant(usize),
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-capacity slab (or arena) that lives in the stack.
+///
+/// Inserting an element returns a `usize` key that stays valid until that element is removed, regardless of what happens to the other elements. Freed slots are reused by later insertions via a free list, so both inserting and removing are O(1).
+///
+/// Note that keys may be reused after their element is removed, so holding on to a stale key may address a newer element.
+#[derive(Clone)]
+pub struct StackSlab<const LEN: usize, T> {
+    /// The slots that have been used so far.
+    entries: StackVec<LEN, Entry<T>>,
+    /// The first free slot, or `entries.len()` if all used slots are occupied.
+    ///
+    /// We implement the StackSlab such that it upholds the following assertion: following the links from `next` visits every [`Entry::Vacant`] exactly once, and ends at `entries.len()`.
+    next:    usize,
+    /// The number of occupied slots.
+    len:     usize,
+}
+impl<const LEN: usize, T> Default for StackSlab<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> StackSlab<LEN, T> {
+    /// Constructor for the StackSlab that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackSlab with no elements in it.
+    #[inline]
+    pub const fn new() -> Self { Self { entries: StackVec::new(), next: 0, len: 0 } }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element into the StackSlab.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Returns
+    /// The key under which `elem` is stored.
+    ///
+    /// # Panics
+    /// This function panics if the StackSlab is already full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, elem: T) -> usize {
+        match self.try_insert(elem) {
+            Ok(key) => key,
+            Err(_) => panic!("Cannot insert element into full StackSlab of capacity {LEN}"),
+        }
+    }
+
+    /// Inserts a new element into the StackSlab, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](StackSlab::insert()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to insert.
+    ///
+    /// # Returns
+    /// The key under which `elem` is stored.
+    ///
+    /// # Errors
+    /// If the StackSlab is already full, a [`CapacityError`] is returned that carries the given `elem`. The slab is untouched in that case.
+    #[inline]
+    pub fn try_insert(&mut self, elem: T) -> Result<usize, CapacityError<T>> {
+        let key: usize = self.next;
+        match self.entries.get_mut(key) {
+            // Reuse a free slot
+            Some(entry) => {
+                let Entry::Vacant(next) = *entry else { unreachable!() };
+                *entry = Entry::Occupied(elem);
+                self.next = next;
+            },
+            // All used slots are occupied, so take a new one
+            None => {
+                if let Err(Entry::Occupied(elem)) = self.entries.push_within_capacity(Entry::Occupied(elem)) {
+                    return Err(CapacityError::new(elem, LEN));
+                }
+                self.next = self.entries.len();
+            },
+        }
+        self.len += 1;
+        Ok(key)
+    }
+
+    /// Returns the key that the next inserted element will get.
+    ///
+    /// This is useful for elements that need to know their own key.
+    ///
+    /// # Returns
+    /// The key of the next insertion, or [`None`] if the StackSlab is full.
+    #[inline]
+    pub const fn vacant_key(&self) -> Option<usize> { if self.len < LEN { Some(self.next) } else { None } }
+
+    /// Removes the element with the given key from the StackSlab.
+    ///
+    /// The key may be reused for elements inserted later.
+    ///
+    /// # Arguments
+    /// - `key`: The key of the element to remove.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if there was no element with that key.
+    #[inline]
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let entry: &mut Entry<T> = self.entries.get_mut(key)?;
+        if let Entry::Vacant(_) = entry {
+            return None;
+        }
+        let Entry::Occupied(elem) = std::mem::replace(entry, Entry::Vacant(self.next)) else { unreachable!() };
+        self.next = key;
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Keeps only the elements for which the given predicate returns true.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that decides whether to keep an element, given its key.
+    #[inline]
+    pub fn retain(&mut self, mut pred: impl FnMut(usize, &mut T) -> bool) {
+        for key in 0..self.entries.len() {
+            let keep: bool = match self.entries.get_mut(key) {
+                Some(Entry::Occupied(elem)) => pred(key, elem),
+                _ => true,
+            };
+            if !keep {
+                self.remove(key);
+            }
+        }
+    }
+
+    /// Removes all elements from the StackSlab.
+    ///
+    /// This also resets the keys, so the next element gets key `0` again.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the element with the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The key of the element.
+    ///
+    /// # Returns
+    /// A reference to the element, or [`None`] if there is no element with that key.
+    #[inline]
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.entries.get(key)? {
+            Entry::Occupied(elem) => Some(elem),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Returns the element with the given key, mutably.
+    ///
+    /// # Arguments
+    /// - `key`: The key of the element.
+    ///
+    /// # Returns
+    /// A mutable reference to the element, or [`None`] if there is no element with that key.
+    #[inline]
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.entries.get_mut(key)? {
+            Entry::Occupied(elem) => Some(elem),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Returns whether there is an element with the given key.
+    ///
+    /// # Arguments
+    /// - `key`: The key to look up.
+    ///
+    /// # Returns
+    /// True if there is, false otherwise.
+    #[inline]
+    pub fn contains(&self, key: usize) -> bool { self.get(key).is_some() }
+
+    /// Returns an iterator over the elements in the StackSlab, in order of their keys.
+    ///
+    /// # Returns
+    /// An iterator yielding each key together with a reference to its element.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (usize, &T)> {
+        self.entries.iter().enumerate().filter_map(|(key, entry)| match entry {
+            Entry::Occupied(elem) => Some((key, elem)),
+            Entry::Vacant(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the elements in the StackSlab, in order of their keys, mutably.
+    ///
+    /// # Returns
+    /// An iterator yielding each key together with a mutable reference to its element.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (usize, &mut T)> {
+        self.entries.iter_mut().enumerate().filter_map(|(key, entry)| match entry {
+            Entry::Occupied(elem) => Some((key, elem)),
+            Entry::Vacant(_) => None,
+        })
+    }
+
+    /// Returns the number of elements in the StackSlab.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns whether there are any elements in the StackSlab.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns whether the StackSlab has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len >= LEN }
+
+    /// Returns the maximum number of elements the StackSlab can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+
+impl<const LEN: usize, T: Debug> Debug for StackSlab<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_map().entries(self.iter()).finish() }
+}
+
+// Indexing
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T> Index<usize> for StackSlab<LEN, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: usize) -> &Self::Output {
+        match self.get(key) {
+            Some(elem) => elem,
+            None => panic!("No element with key {key} in StackSlab"),
+        }
+    }
+}
+#[cfg(not(feature = "no-panic"))]
+impl<const LEN: usize, T> IndexMut<usize> for StackSlab<LEN, T> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        match self.get_mut(key) {
+            Some(elem) => elem,
+            None => panic!("No element with key {key} in StackSlab"),
+        }
+    }
+}
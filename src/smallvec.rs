@@ -0,0 +1,54 @@
+//  Description:
+//!   Implements conversions between [`StackVec`]s and [`SmallVec`]s,
+//!   available under the `smallvec` feature.
+//!
+//!   Because [`SmallVec`]s may spill to the heap, converting a StackVec to
+//!   one always succeeds, whereas converting one to a StackVec is fallible.
+//
+
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+use smallvec::SmallVec;
+
+use crate::{CapacityError, StackVec};
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, const LEN2: usize, T, P> TryFrom<SmallVec<[T; LEN2]>> for StackVec<LEN, T, P> {
+    type Error = CapacityError<SmallVec<[T; LEN2]>>;
+
+    #[inline]
+    fn try_from(mut value: SmallVec<[T; LEN2]>) -> Result<Self, Self::Error> {
+        let len: usize = value.len();
+        if len > LEN {
+            return Err(CapacityError::with_excess(value, LEN, len - LEN));
+        }
+
+        let mut stack: Self = Self::new();
+        // SAFETY: We asserted the elements fit in the StackVec, and the two buffers are obviously distinct.
+        unsafe { std::ptr::copy_nonoverlapping(value.as_ptr(), stack.as_mut_ptr(), len) };
+        // SAFETY: We just moved the elements out of the SmallVec, so it must forget about them (while still freeing its heap buffer, if any); then, we just initialized the first `len` elements of the StackVec.
+        unsafe {
+            value.set_len(0);
+            stack.set_len(len);
+        }
+        Ok(stack)
+    }
+}
+impl<const LEN: usize, const LEN2: usize, T, P> From<StackVec<LEN, T, P>> for SmallVec<[T; LEN2]> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        if LEN > LEN2 {
+            // Might not fit inline, so let the SmallVec decide
+            return value.into_iter().collect();
+        }
+
+        // Move the elements over in one go, preventing the StackVec from dropping them
+        let value: ManuallyDrop<StackVec<LEN, T, P>> = ManuallyDrop::new(value);
+        let mut buf: MaybeUninit<[T; LEN2]> = MaybeUninit::uninit();
+        // SAFETY: We know `value.len <= LEN <= LEN2`, so the elements fit in `buf`, and the two buffers are obviously distinct.
+        unsafe { std::ptr::copy_nonoverlapping(value.as_ptr(), buf.as_mut_ptr() as *mut T, value.len) };
+        // SAFETY: We just initialized the first `value.len` elements of `buf`, and `value` never drops them again.
+        unsafe { SmallVec::from_buf_and_len_unchecked(buf, value.len) }
+    }
+}
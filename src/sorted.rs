@@ -0,0 +1,320 @@
+//  Description:
+//!   Implements a [`SortedStackVec`], a [`StackVec`] that keeps its elements
+//!   sorted.
+//
+
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::ops::{Bound, Deref, RangeBounds};
+
+use crate::{CapacityError, IntoIter, StackVec};
+
+
+/***** LIBRARY *****/
+/// Implements a [`StackVec`] that keeps its elements sorted in ascending order.
+///
+/// Elements are inserted at the position found by a binary search, so lookups (e.g., [`Self::contains()`](SortedStackVec::contains()) and [`Self::range()`](SortedStackVec::range())) are O(log n), while insertions and removals are O(n). This makes it a tiny ordered index that doesn't need to allocate.
+///
+/// It dereferences to a slice for read-only access; it can't be mutated in ways that would break the order.
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SortedStackVec<const LEN: usize, T> {
+    /// The elements of the vec.
+    ///
+    /// We implement the SortedStackVec such that it upholds the following assertion: `vec` is sorted in ascending order.
+    vec: StackVec<LEN, T>,
+}
+impl<const LEN: usize, T> Default for SortedStackVec<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> SortedStackVec<LEN, T> {
+    /// Constructor for the SortedStackVec that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new SortedStackVec with no elements in it.
+    #[inline]
+    pub const fn new() -> Self { Self { vec: StackVec::new() } }
+
+    /// Returns the elements in the SortedStackVec.
+    ///
+    /// # Returns
+    /// A slice of all elements, in ascending order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { self.vec.as_slice() }
+
+    /// Returns the elements of the SortedStackVec as a StackVec.
+    ///
+    /// # Returns
+    /// The StackVec that backs this SortedStackVec, which is sorted.
+    #[inline]
+    pub fn into_stackvec(self) -> StackVec<LEN, T> { self.vec }
+
+    /// Removes an element from the SortedStackVec.
+    ///
+    /// # Arguments
+    /// - `idx`: The index of the element to remove.
+    ///
+    /// # Returns
+    /// The removed element, or else [`None`] if the `idx` is out-of-bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> Option<T> { self.vec.remove(idx) }
+
+    /// Removes the greatest element from the SortedStackVec.
+    ///
+    /// # Returns
+    /// The last element, or [`None`] if the SortedStackVec is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> { self.vec.pop() }
+
+    /// Shortens the SortedStackVec to the given length, keeping the smallest elements.
+    ///
+    /// Does nothing if the SortedStackVec is already shorter.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the SortedStackVec.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) { self.vec.truncate(len) }
+
+    /// Keeps only the elements for which the given predicate returns true.
+    ///
+    /// # Arguments
+    /// - `pred`: A closure that decides whether to keep an element.
+    #[inline]
+    pub fn retain(&mut self, mut pred: impl FnMut(&T) -> bool) { self.vec.extract_if(|elem| !pred(elem)).for_each(drop) }
+
+    /// Removes all elements from the SortedStackVec.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Returns whether the SortedStackVec has reached its capacity.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.vec.is_full() }
+
+    /// Returns the maximum number of elements the SortedStackVec can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<const LEN: usize, T: Ord> SortedStackVec<LEN, T> {
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element such that the SortedStackVec stays sorted.
+    ///
+    /// If there are elements equal to `elem`, it is inserted after them.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted.
+    ///
+    /// # Panics
+    /// This function panics if the SortedStackVec is already full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, elem: T) -> usize {
+        match self.try_insert(elem) {
+            Ok(idx) => idx,
+            Err(_) => panic!("Cannot insert element into full SortedStackVec of capacity {LEN}"),
+        }
+    }
+
+    /// Inserts a new element such that the SortedStackVec stays sorted, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert()`](SortedStackVec::insert()).
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted.
+    ///
+    /// # Errors
+    /// If the SortedStackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn try_insert(&mut self, elem: T) -> Result<usize, CapacityError<T>> { self.vec.insert_sorted(elem) }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Inserts a new element such that the SortedStackVec stays sorted, unless an equal element is already in it.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert. Dropped if an equal element is already in the vec.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted, or [`None`] if it was a duplicate.
+    ///
+    /// # Panics
+    /// This function panics if `elem` is not a duplicate, and the SortedStackVec is already full.
+    #[inline]
+    #[track_caller]
+    pub fn insert_unique(&mut self, elem: T) -> Option<usize> {
+        match self.try_insert_unique(elem) {
+            Ok(idx) => idx,
+            Err(_) => panic!("Cannot insert element into full SortedStackVec of capacity {LEN}"),
+        }
+    }
+
+    /// Inserts a new element such that the SortedStackVec stays sorted, unless an equal element is already in it, returning an error if there isn't enough space for it.
+    ///
+    /// This is the non-panicking counterpart of [`Self::insert_unique()`](SortedStackVec::insert_unique()). Inserting a duplicate always succeeds.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert. Dropped if an equal element is already in the vec.
+    ///
+    /// # Returns
+    /// The index at which `elem` was inserted, or [`None`] if it was a duplicate.
+    ///
+    /// # Errors
+    /// If `elem` is not a duplicate and the SortedStackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn try_insert_unique(&mut self, elem: T) -> Result<Option<usize>, CapacityError<T>> {
+        match self.vec.binary_search(&elem) {
+            Ok(_) => Ok(None),
+            Err(idx) => {
+                // NOTE: The index is always in-bounds, so the only thing that can go wrong is capacity
+                self.vec.try_insert(idx, elem).map_err(|err| CapacityError::new(err.into_element(), LEN))?;
+                Ok(Some(idx))
+            },
+        }
+    }
+
+    /// Inserts a new element such that the SortedStackVec stays sorted, replacing an equal element if there is one.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element to insert.
+    ///
+    /// # Returns
+    /// The element that was replaced, or [`None`] if there was none.
+    ///
+    /// # Errors
+    /// If there is no equal element and the SortedStackVec is already full, a [`CapacityError`] is returned that carries the given `elem`. The vec is untouched in that case.
+    #[inline]
+    pub fn replace(&mut self, elem: T) -> Result<Option<T>, CapacityError<T>> {
+        match self.vec.binary_search(&elem) {
+            Ok(idx) => Ok(self.vec.replace(idx, elem)),
+            Err(idx) => {
+                // NOTE: The index is always in-bounds, so the only thing that can go wrong is capacity
+                self.vec.try_insert(idx, elem).map_err(|err| CapacityError::new(err.into_element(), LEN))?;
+                Ok(None)
+            },
+        }
+    }
+
+    /// Returns whether the SortedStackVec contains an element equal to the given one.
+    ///
+    /// This is O(log n).
+    ///
+    /// # Arguments
+    /// - `elem`: The element to look for. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// True if it does, false otherwise.
+    #[inline]
+    pub fn contains<Q: ?Sized + Ord>(&self, elem: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.vec.binary_search_by(|e| e.borrow().cmp(elem)).is_ok()
+    }
+
+    /// Removes an element equal to the given one from the SortedStackVec.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to remove. May be any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if there was none equal to `elem`.
+    #[inline]
+    pub fn remove_item<Q: ?Sized + Ord>(&mut self, elem: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        let idx: usize = self.vec.binary_search_by(|e| e.borrow().cmp(elem)).ok()?;
+        self.vec.remove(idx)
+    }
+
+    /// Returns the elements that fall in the given range.
+    ///
+    /// This is O(log n).
+    ///
+    /// # Arguments
+    /// - `range`: The range of values (not indices) to find. May be over any borrowed form of `T`.
+    ///
+    /// # Returns
+    /// A slice of the elements in `range`, in ascending order.
+    #[inline]
+    pub fn range<Q: ?Sized + Ord>(&self, range: impl RangeBounds<Q>) -> &[T]
+    where
+        T: Borrow<Q>,
+    {
+        let data: &[T] = self.vec.as_slice();
+        let start: usize = match range.start_bound() {
+            Bound::Included(start) => data.partition_point(|e| e.borrow() < start),
+            Bound::Excluded(start) => data.partition_point(|e| e.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(end) => data.partition_point(|e| e.borrow() <= end),
+            Bound::Excluded(end) => data.partition_point(|e| e.borrow() < end),
+            Bound::Unbounded => data.len(),
+        };
+        // NOTE: An empty or inverted range yields an empty slice instead of panicking
+        &data[start..end.max(start)]
+    }
+}
+
+impl<const LEN: usize, T: Debug> Debug for SortedStackVec<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_slice(), f) }
+}
+
+// Deref
+impl<const LEN: usize, T> Deref for SortedStackVec<LEN, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+impl<const LEN: usize, T> AsRef<[T]> for SortedStackVec<LEN, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+// Iteration
+impl<const LEN: usize, T> IntoIterator for SortedStackVec<LEN, T> {
+    type Item = T;
+    type IntoIter = IntoIter<LEN, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
+}
+impl<'s, const LEN: usize, T> IntoIterator for &'s SortedStackVec<LEN, T> {
+    type Item = &'s T;
+    type IntoIter = std::slice::Iter<'s, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_slice().iter() }
+}
+
+// Conversion
+impl<const LEN: usize, T: Ord, P> From<StackVec<LEN, T, P>> for SortedStackVec<LEN, T> {
+    /// Sorts the elements of a [`StackVec`] in-place, without allocating.
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        let mut vec: StackVec<LEN, T> = value.with_policy();
+        vec.sort_unstable();
+        Self { vec }
+    }
+}
+impl<const LEN: usize, T> From<SortedStackVec<LEN, T>> for StackVec<LEN, T> {
+    #[inline]
+    fn from(value: SortedStackVec<LEN, T>) -> Self { value.vec }
+}
+impl<const LEN: usize, const LEN2: usize, T: PartialEq> PartialEq<[T; LEN2]> for SortedStackVec<LEN, T> {
+    #[inline]
+    fn eq(&self, other: &[T; LEN2]) -> bool { self.as_slice() == other }
+}
@@ -0,0 +1,298 @@
+//  Description:
+//!   Implements an [`SpscQueue`], a lock-free single-producer,
+//!   single-consumer queue with fixed capacity that lives in the stack.
+//
+
+use std::cell::UnsafeCell;
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::{CapacityError, const_assert};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Advances an index of an [`SpscQueue`] by one.
+///
+/// Indices run from `0` up to `2 * LEN` (exclusive), so that a full queue can be told apart from an empty one.
+///
+/// # Arguments
+/// - `idx`: The index to advance.
+///
+/// # Returns
+/// The next index, wrapping around at `2 * LEN`.
+#[inline]
+const fn next<const LEN: usize>(idx: usize) -> usize { if idx + 1 == 2 * LEN { 0 } else { idx + 1 } }
+
+/// Computes the number of elements between two indices of an [`SpscQueue`].
+///
+/// # Arguments
+/// - `head`: The index of the first element.
+/// - `tail`: The index right after the last element.
+///
+/// # Returns
+/// The number of elements in the queue.
+#[inline]
+const fn distance<const LEN: usize>(head: usize, tail: usize) -> usize { if tail >= head { tail - head } else { tail + 2 * LEN - head } }
+
+
+
+
+
+/***** LIBRARY *****/
+/// Implements a lock-free, single-producer, single-consumer queue over `LEN` inline slots.
+///
+/// Call [`Self::split()`](SpscQueue::split()) to get a [`Producer`] and a [`Consumer`] handle, which can be sent to different threads (e.g., an interrupt handler and the main loop). Pushing and popping only use atomic loads and stores, never locks or allocation.
+///
+/// For a queue in a `static`, which can't be borrowed mutably, use [`Self::split_static()`](SpscQueue::split_static()) instead.
+///
+/// Refuses to compile if `LEN` is zero.
+pub struct SpscQueue<const LEN: usize, T> {
+    /// The slots of the queue.
+    data: [UnsafeCell<MaybeUninit<T>>; LEN],
+    /// The index of the first element. Only written by the consumer.
+    head: AtomicUsize,
+    /// The index right after the last element. Only written by the producer.
+    ///
+    /// We implement the SpscQueue such that it upholds the following assertion: the slots from `head` up to `tail` (both modulo `LEN`) are initialized.
+    tail: AtomicUsize,
+    /// Whether [`Self::split_static()`](SpscQueue::split_static()) has handed out the handles already.
+    split: AtomicBool,
+}
+// SAFETY: Through a shared reference, the slots are only accessed by the `Producer` and `Consumer`, of which there is at most one each (as `split()` borrows mutably and `split_static()` only succeeds once). So the queue can be shared between threads as long as the elements can be sent.
+unsafe impl<const LEN: usize, T: Send> Sync for SpscQueue<LEN, T> {}
+impl<const LEN: usize, T> Default for SpscQueue<LEN, T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<const LEN: usize, T> SpscQueue<LEN, T> {
+    /// Constructor for the SpscQueue that initializes it as empty.
+    ///
+    /// Refuses to compile if `LEN` is zero. Being `const`, it can be used to initialize `static`s (see [`Self::split_static()`](SpscQueue::split_static())).
+    ///
+    /// # Returns
+    /// A new SpscQueue with no elements in it.
+    #[inline]
+    pub const fn new() -> Self {
+        // There must be at least one slot, or the indices can't wrap
+        const { const_assert(LEN > 0, "Cannot create an SpscQueue of capacity 0") };
+
+        Self {
+            // SAFETY: We can do this because the "initialization" actually leaves us with uninitialized elements, still. `UnsafeCell` has the same layout as its contents.
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            split: AtomicBool::new(false),
+        }
+    }
+
+    /// Splits the SpscQueue into a producer and a consumer handle.
+    ///
+    /// Because this borrows the queue mutably, there can only ever be one of each at a time.
+    ///
+    /// # Returns
+    /// A tuple of the [`Producer`], which pushes elements, and the [`Consumer`], which pops them.
+    #[inline]
+    pub fn split(&mut self) -> (Producer<'_, LEN, T>, Consumer<'_, LEN, T>) { (Producer { queue: self }, Consumer { queue: self }) }
+
+    /// Splits an SpscQueue that lives in a `static` into a producer and a consumer handle.
+    ///
+    /// Since a `static` can't be borrowed mutably (without `unsafe`), this only hands out the handles the first time it is called. E.g., the main loop can split the queue at startup and move the [`Producer`] to where an interrupt handler can reach it.
+    ///
+    /// # Returns
+    /// A tuple of the [`Producer`], which pushes elements, and the [`Consumer`], which pops them, or [`None`] if the queue has been split this way before.
+    #[inline]
+    pub fn split_static(&'static self) -> Option<(Producer<'static, LEN, T>, Consumer<'static, LEN, T>)> {
+        if self.split.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        Some((Producer { queue: self }, Consumer { queue: self }))
+    }
+
+    /// Returns the slot for the given index.
+    ///
+    /// # Arguments
+    /// - `idx`: The index, in the range `0..2 * LEN`.
+    ///
+    /// # Returns
+    /// A raw pointer to the slot.
+    #[inline]
+    fn slot(&self, idx: usize) -> *mut MaybeUninit<T> { self.data[idx % LEN].get() }
+
+    /// Returns the number of elements in the SpscQueue.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub fn len(&self) -> usize { distance::<LEN>(self.head.load(Ordering::Acquire), self.tail.load(Ordering::Acquire)) }
+
+    /// Returns whether there are any elements in the SpscQueue.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the maximum number of elements the SpscQueue can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<const LEN: usize, T> Debug for SpscQueue<LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_struct("SpscQueue").field("len", &self.len()).field("capacity", &LEN).finish() }
+}
+impl<const LEN: usize, T> Drop for SpscQueue<LEN, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // Drop any elements that were never popped
+        let (_, mut consumer): (Producer<LEN, T>, Consumer<LEN, T>) = self.split();
+        while consumer.pop().is_some() {}
+    }
+}
+
+
+
+/// The pushing half of an [`SpscQueue`].
+///
+/// Returned by [`SpscQueue::split()`].
+pub struct Producer<'q, const LEN: usize, T> {
+    /// The queue we push to.
+    queue: &'q SpscQueue<LEN, T>,
+}
+// SAFETY: The producer only ever writes to slots that the consumer has released (and vice versa), so it can be sent to another thread as long as the elements can.
+unsafe impl<'q, const LEN: usize, T: Send> Send for Producer<'q, LEN, T> {}
+impl<'q, const LEN: usize, T> Producer<'q, LEN, T> {
+    /// Pushes a new element to the back of the queue, returning an error if there isn't enough space for it.
+    ///
+    /// # Arguments
+    /// - `elem`: The new element (of type `T`) to push.
+    ///
+    /// # Errors
+    /// If the queue is full, a [`CapacityError`] is returned that carries the given `elem`. The queue is untouched in that case.
+    #[inline]
+    pub fn try_push(&mut self, elem: T) -> Result<(), CapacityError<T>> {
+        // We own the tail, but must see the latest head to know which slots have been released
+        let tail: usize = self.queue.tail.load(Ordering::Relaxed);
+        if distance::<LEN>(self.queue.head.load(Ordering::Acquire), tail) >= LEN {
+            return Err(CapacityError::new(elem, LEN));
+        }
+
+        // SAFETY: The slot at `tail` is outside of the initialized range, and only the producer writes to it, so nobody else accesses it right now.
+        unsafe { (*self.queue.slot(tail)).write(elem) };
+        // SAFETY: This upholds our `self.tail` assertion, as we just initialized the slot. The release makes sure the consumer sees the element before the new tail.
+        self.queue.tail.store(next::<LEN>(tail), Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns whether the queue is full.
+    ///
+    /// Note that the consumer may pop elements at any time, so this may be outdated by the time it returns.
+    ///
+    /// # Returns
+    /// True if no more elements fit, false otherwise.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.queue.len() >= LEN }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// Note that the consumer may pop elements at any time, so this may be outdated by the time it returns.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub fn len(&self) -> usize { self.queue.len() }
+
+    /// Returns whether there are any elements in the queue.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.queue.is_empty() }
+
+    /// Returns the maximum number of elements the queue can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<'q, const LEN: usize, T> Debug for Producer<'q, LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_tuple("Producer").field(self.queue).finish() }
+}
+
+/// The popping half of an [`SpscQueue`].
+///
+/// Returned by [`SpscQueue::split()`].
+pub struct Consumer<'q, const LEN: usize, T> {
+    /// The queue we pop from.
+    queue: &'q SpscQueue<LEN, T>,
+}
+// SAFETY: The consumer only ever reads from slots that the producer has filled (and vice versa), so it can be sent to another thread as long as the elements can.
+unsafe impl<'q, const LEN: usize, T: Send> Send for Consumer<'q, LEN, T> {}
+impl<'q, const LEN: usize, T> Consumer<'q, LEN, T> {
+    /// Removes the first element from the queue.
+    ///
+    /// # Returns
+    /// The removed element, or [`None`] if the queue was empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        // We own the head, but must see the latest tail to know which slots have been filled
+        let head: usize = self.queue.head.load(Ordering::Relaxed);
+        if head == self.queue.tail.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // SAFETY: The slot at `head` is within the initialized range, and the producer doesn't touch it until we move the head past it.
+        let elem: T = unsafe { (*self.queue.slot(head)).assume_init_read() };
+        // SAFETY: We moved the element out, so the slot can be released. The release makes sure we're done reading before the producer overwrites it.
+        self.queue.head.store(next::<LEN>(head), Ordering::Release);
+        Some(elem)
+    }
+
+    /// Returns the first element in the queue, without removing it.
+    ///
+    /// # Returns
+    /// A reference to the first element, or [`None`] if the queue is empty.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        let head: usize = self.queue.head.load(Ordering::Relaxed);
+        if head == self.queue.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        // SAFETY: The slot at `head` is initialized, and stays so for as long as we borrow the consumer (as only it can pop).
+        Some(unsafe { (*self.queue.slot(head)).assume_init_ref() })
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// Note that the producer may push elements at any time, so this may be outdated by the time it returns.
+    ///
+    /// # Returns
+    /// The number of elements currently in it.
+    #[inline]
+    pub fn len(&self) -> usize { self.queue.len() }
+
+    /// Returns whether there are any elements in the queue.
+    ///
+    /// Note that the producer may push elements at any time, so this may be outdated by the time it returns.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.queue.is_empty() }
+
+    /// Returns the maximum number of elements the queue can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+}
+impl<'q, const LEN: usize, T> Debug for Consumer<'q, LEN, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { f.debug_tuple("Consumer").field(self.queue).finish() }
+}
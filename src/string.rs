@@ -0,0 +1,309 @@
+//  Description:
+//!   Implements a [`StackString`], a fixed-capacity UTF-8 string that
+//!   lives in the stack.
+//
+
+use std::borrow::Borrow;
+use std::fmt::{Arguments, Debug, Display, Error as FError, Formatter, Result as FResult, Write as FWrite};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::str::{FromStr, Utf8Error};
+
+use crate::{CapacityError, StackVec};
+
+
+/***** LIBRARY *****/
+/// Implements a fixed-capacity, UTF-8 encoded string that lives in the stack.
+///
+/// It is backed by a byte [`StackVec`] of capacity `LEN`, so it can hold at most `LEN` bytes (not characters). All operations keep the contents valid UTF-8.
+#[derive(Clone, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct StackString<const LEN: usize> {
+    /// The bytes of the string.
+    ///
+    /// We implement the StackString such that it upholds the following assertion: `vec` is valid UTF-8.
+    vec: StackVec<LEN, u8>,
+}
+impl<const LEN: usize> StackString<LEN> {
+    /// Constructor for the StackString that initializes it as empty.
+    ///
+    /// # Returns
+    /// A new StackString with no characters in it.
+    #[inline]
+    pub const fn new() -> Self { Self { vec: StackVec::new() } }
+
+    /// Constructor for the StackString that takes over the bytes of a StackVec.
+    ///
+    /// # Arguments
+    /// - `vec`: The bytes to take over.
+    ///
+    /// # Returns
+    /// A new StackString with the bytes in `vec`.
+    ///
+    /// # Errors
+    /// This function errors if `vec` is not valid UTF-8.
+    #[inline]
+    pub fn from_utf8(vec: StackVec<LEN, u8>) -> Result<Self, Utf8Error> {
+        std::str::from_utf8(vec.as_slice())?;
+        Ok(Self { vec })
+    }
+
+    /// Returns the bytes of the StackString as a StackVec.
+    ///
+    /// # Returns
+    /// The StackVec that backs this StackString.
+    #[inline]
+    pub fn into_bytes(self) -> StackVec<LEN, u8> { self.vec }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Appends a character to the end of the StackString.
+    ///
+    /// # Arguments
+    /// - `c`: The character to append.
+    ///
+    /// # Panics
+    /// This function panics if the encoded character does not fit in the StackString anymore.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, c: char) {
+        if self.try_push(c).is_err() {
+            panic!("Cannot push character of {} bytes to StackString of length {} and capacity {}", c.len_utf8(), self.len(), LEN);
+        }
+    }
+
+    /// Appends a character to the end of the StackString, returning an error if it doesn't fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push()`](StackString::push()).
+    ///
+    /// # Arguments
+    /// - `c`: The character to append.
+    ///
+    /// # Errors
+    /// If the encoded character does not fit, a [`CapacityError`] is returned that carries `c`. The string is untouched in that case.
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        let mut buf: [u8; 4] = [0; 4];
+        self.try_push_str(c.encode_utf8(&mut buf)).map_err(|err| CapacityError::with_excess(c, LEN, err.excess()))
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Appends a string to the end of the StackString.
+    ///
+    /// # Arguments
+    /// - `s`: The string to append.
+    ///
+    /// # Panics
+    /// This function panics if `s` does not fit in the StackString. In that case, the string is untouched.
+    #[inline]
+    #[track_caller]
+    pub fn push_str(&mut self, s: &str) {
+        if self.try_push_str(s).is_err() {
+            panic!("Cannot push string of {} bytes to StackString of length {} and capacity {}", s.len(), self.len(), LEN);
+        }
+    }
+
+    /// Appends a string to the end of the StackString, returning an error if it doesn't fit.
+    ///
+    /// This is the non-panicking counterpart of [`Self::push_str()`](StackString::push_str()). Either the whole string is appended, or nothing is.
+    ///
+    /// # Arguments
+    /// - `s`: The string to append.
+    ///
+    /// # Errors
+    /// If `s` does not fit, a [`CapacityError`] is returned that carries the number of bytes that would not have fit. The string is untouched in that case.
+    #[inline]
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> { self.vec.try_extend_from_copy_slice(s.as_bytes()) }
+
+    /// Removes the last character from the StackString.
+    ///
+    /// # Returns
+    /// The removed character, or [`None`] if the StackString was empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c: char = self.as_str().chars().next_back()?;
+        // NOTE: This is always a character boundary, as we just found the character before it
+        self.vec.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    /// Shortens the StackString to the given length in bytes.
+    ///
+    /// Does nothing if the StackString is already shorter.
+    ///
+    /// # Arguments
+    /// - `len`: The new length of the StackString, in bytes.
+    ///
+    /// # Panics
+    /// This function panics if `len` does not lie on a character boundary.
+    #[inline]
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            if !self.as_str().is_char_boundary(len) {
+                panic!("Cannot truncate StackString to length {len}, as it does not lie on a character boundary");
+            }
+            self.vec.truncate(len);
+        }
+    }
+
+    /// Removes all characters from the StackString.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Returns the length of the StackString, in bytes.
+    ///
+    /// # Returns
+    /// The number of bytes (not characters) in the StackString.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() }
+
+    /// Returns whether there are any characters in the StackString.
+    ///
+    /// # Returns
+    /// True if there aren't any, or false otherwise.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.vec.is_empty() }
+
+    /// Returns whether the StackString has reached its capacity.
+    ///
+    /// Note that a StackString that isn't full may still not have room for a particular character, as characters take up to 4 bytes.
+    ///
+    /// # Returns
+    /// True if no more bytes fit, false otherwise.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.vec.is_full() }
+
+    /// Returns the maximum number of bytes the StackString can hold.
+    ///
+    /// # Returns
+    /// `LEN`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { LEN }
+
+    /// Returns the number of bytes that can still be pushed to the StackString.
+    ///
+    /// # Returns
+    /// The capacity minus the length.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { self.vec.remaining_capacity() }
+
+    /// Returns the StackString as a string slice.
+    ///
+    /// # Returns
+    /// A [`&str`] borrowing the characters of the StackString.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: We use our assertion for `self.vec` that it is valid UTF-8.
+        unsafe { std::str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    /// Returns the StackString as a mutable string slice.
+    ///
+    /// # Returns
+    /// A [`&mut str`](str) borrowing the characters of the StackString.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: We use our assertion for `self.vec` that it is valid UTF-8, and `str` makes sure it stays that way.
+        unsafe { std::str::from_utf8_unchecked_mut(self.vec.as_slice_mut()) }
+    }
+
+    /// Returns the bytes of the StackString.
+    ///
+    /// # Returns
+    /// A slice of the UTF-8 encoded bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { self.vec.as_slice() }
+}
+
+impl<const LEN: usize> Debug for StackString<LEN> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Debug::fmt(self.as_str(), f) }
+}
+impl<const LEN: usize> Display for StackString<LEN> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult { Display::fmt(self.as_str(), f) }
+}
+impl<const LEN: usize> Hash for StackString<LEN> {
+    /// Hashes the StackString like a [`str`], as required by its [`Borrow<str>`] implementation.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_str().hash(state) }
+}
+impl<const LEN: usize> FWrite for StackString<LEN> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> FResult { self.try_push_str(s).map_err(|_| FError) }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments) -> FResult {
+        // NOTE: The byte StackVec only ever writes whole `str`s and rolls back all of them on failure, so we stay valid UTF-8
+        self.vec.write_fmt(args)
+    }
+}
+
+impl<const LEN: usize> Deref for StackString<LEN> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_str() }
+}
+impl<const LEN: usize> DerefMut for StackString<LEN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_str() }
+}
+impl<const LEN: usize> AsRef<str> for StackString<LEN> {
+    #[inline]
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+impl<const LEN: usize> AsRef<[u8]> for StackString<LEN> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] { self.as_bytes() }
+}
+impl<const LEN: usize> Borrow<str> for StackString<LEN> {
+    #[inline]
+    fn borrow(&self) -> &str { self.as_str() }
+}
+
+impl<const LEN: usize> PartialEq<str> for StackString<LEN> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+impl<'s, const LEN: usize> PartialEq<&'s str> for StackString<LEN> {
+    #[inline]
+    fn eq(&self, other: &&'s str) -> bool { self.as_str() == *other }
+}
+impl<const LEN: usize> PartialEq<String> for StackString<LEN> {
+    #[inline]
+    fn eq(&self, other: &String) -> bool { self.as_str() == other.as_str() }
+}
+impl<const LEN: usize> PartialEq<StackString<LEN>> for str {
+    #[inline]
+    fn eq(&self, other: &StackString<LEN>) -> bool { self == other.as_str() }
+}
+impl<const LEN: usize> PartialEq<StackString<LEN>> for &str {
+    #[inline]
+    fn eq(&self, other: &StackString<LEN>) -> bool { *self == other.as_str() }
+}
+impl<const LEN: usize> PartialEq<StackString<LEN>> for String {
+    #[inline]
+    fn eq(&self, other: &StackString<LEN>) -> bool { self.as_str() == other.as_str() }
+}
+
+impl<const LEN: usize> FromStr for StackString<LEN> {
+    type Err = CapacityError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res: Self = Self::new();
+        res.try_push_str(s)?;
+        Ok(res)
+    }
+}
+impl<const LEN: usize> TryFrom<&str> for StackString<LEN> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> { Self::from_str(value) }
+}
+impl<const LEN: usize> From<StackString<LEN>> for String {
+    #[inline]
+    fn from(value: StackString<LEN>) -> Self { value.as_str().into() }
+}
@@ -0,0 +1,67 @@
+//  Description:
+//!   Implements conversions between [`StackVec`]s and [`tinyvec`]'s
+//!   [`ArrayVec`]s and [`TinyVec`]s, available under the `tinyvec` feature.
+//!
+//!   Like [`From<[T; N]>`](StackVec::from()), conversions between StackVecs
+//!   and [`ArrayVec`]s only compile if the target's capacity is at least the
+//!   source's capacity. Because [`TinyVec`]s may spill to the heap,
+//!   converting them to StackVecs is fallible instead.
+//
+
+use std::mem::MaybeUninit;
+
+use tinyvec::{ArrayVec, TinyVec};
+
+use crate::{CapacityError, StackVec, const_assert};
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, const LEN2: usize, T: Default, P> From<ArrayVec<[T; LEN2]>> for StackVec<LEN, T, P> {
+    #[inline]
+    fn from(value: ArrayVec<[T; LEN2]>) -> Self {
+        // Refuse to compile for vecs that may not fit
+        const { const_assert(LEN2 <= LEN, "Cannot create a StackVec from a tinyvec::ArrayVec with a larger capacity") };
+
+        let mut stack: Self = Self::new();
+        for elem in value {
+            stack.data[stack.len] = MaybeUninit::new(elem);
+            stack.len += 1;
+        }
+        stack
+    }
+}
+impl<const LEN: usize, const LEN2: usize, T: Default, P> From<StackVec<LEN, T, P>> for ArrayVec<[T; LEN2]> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self {
+        // Refuse to compile for vecs that may not fit
+        const { const_assert(LEN <= LEN2, "Cannot create a tinyvec::ArrayVec from a StackVec with a larger capacity") };
+
+        // NOTE: Can't panic, as we asserted all elements fit
+        let mut vec: Self = Self::new();
+        vec.extend(value);
+        vec
+    }
+}
+
+impl<const LEN: usize, const LEN2: usize, T: Default, P> TryFrom<TinyVec<[T; LEN2]>> for StackVec<LEN, T, P> {
+    type Error = CapacityError<TinyVec<[T; LEN2]>>;
+
+    #[inline]
+    fn try_from(value: TinyVec<[T; LEN2]>) -> Result<Self, Self::Error> {
+        if value.len() > LEN {
+            let excess: usize = value.len() - LEN;
+            return Err(CapacityError::with_excess(value, LEN, excess));
+        }
+
+        let mut stack: Self = Self::new();
+        for elem in value {
+            stack.data[stack.len] = MaybeUninit::new(elem);
+            stack.len += 1;
+        }
+        Ok(stack)
+    }
+}
+impl<const LEN: usize, const LEN2: usize, T: Default, P> From<StackVec<LEN, T, P>> for TinyVec<[T; LEN2]> {
+    #[inline]
+    fn from(value: StackVec<LEN, T, P>) -> Self { value.into_iter().collect() }
+}
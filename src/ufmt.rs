@@ -0,0 +1,34 @@
+//  Description:
+//!   Implements [`ufmt`]'s [`uDebug`] and [`uDisplay`] for the [`StackVec`],
+//!   available under the `ufmt` feature.
+//
+
+use ufmt::{Formatter, uDebug, uDisplay, uWrite};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T: uDebug, P> uDebug for StackVec<LEN, T, P> {
+    #[inline]
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let mut list = f.debug_list()?;
+        for elem in self {
+            list.entry(elem)?;
+        }
+        list.finish()
+    }
+}
+impl<const LEN: usize, T: uDisplay, P> uDisplay for StackVec<LEN, T, P> {
+    #[inline]
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("[")?;
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            uDisplay::fmt(elem, f)?;
+        }
+        f.write_str("]")
+    }
+}
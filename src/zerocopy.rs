@@ -0,0 +1,87 @@
+//  Description:
+//!   Adds conversions between [`StackVec`]s and raw bytes using
+//!   [`zerocopy`]'s traits, available under the `zerocopy` feature.
+//!
+//!   Note that the StackVec itself cannot implement [`IntoBytes`] or
+//!   [`FromBytes`], as its unused capacity is uninitialized and its length
+//!   must not exceed its capacity. To emit a StackVec's elements, use the
+//!   slice implementation instead (e.g., `stack_vec.as_slice().as_bytes()`).
+//
+
+use std::mem::{MaybeUninit, size_of};
+
+use zerocopy::{FromBytes, Immutable, IntoBytes};
+
+use crate::StackVec;
+
+
+/***** LIBRARY *****/
+impl<const LEN: usize, T: FromBytes, P> StackVec<LEN, T, P> {
+    /// Reads a StackVec from raw bytes, such as a network buffer.
+    ///
+    /// The bytes do not have to be aligned for `T`.
+    ///
+    /// # Arguments
+    /// - `bytes`: The bytes to read the elements from. Must contain a whole number of elements.
+    ///
+    /// # Returns
+    /// A new StackVec with all elements encoded in `bytes`, or [`None`] if `bytes` does not contain a whole number of `T`s or contains more than `LEN` of them.
+    #[inline]
+    pub fn read_from_bytes(bytes: &[u8]) -> Option<Self> {
+        match Self::read_from_prefix(bytes, bytes.len() / Self::ELEM_SIZE) {
+            Some((stack, [])) => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Reads a StackVec from the start of some raw bytes, such as a network buffer.
+    ///
+    /// The bytes do not have to be aligned for `T`.
+    ///
+    /// # Arguments
+    /// - `bytes`: The bytes to read the elements from.
+    /// - `len`: The number of elements to read (e.g., from a length prefix).
+    ///
+    /// # Returns
+    /// A tuple of a new StackVec with the first `len` elements encoded in `bytes` and the remaining bytes, or [`None`] if `len` exceeds `LEN` or `bytes` is too short.
+    #[inline]
+    pub fn read_from_prefix(bytes: &[u8], len: usize) -> Option<(Self, &[u8])> {
+        if len > LEN || bytes.len() / Self::ELEM_SIZE < len {
+            return None;
+        }
+
+        let (elems, rest): (&[u8], &[u8]) = bytes.split_at(len * Self::ELEM_SIZE);
+        let mut stack: Self = Self::new();
+        for chunk in elems.chunks_exact(Self::ELEM_SIZE) {
+            // NOTE: Can't fail, as we read at most `LEN` chunks of exactly the right size
+            stack.data[stack.len] = MaybeUninit::new(T::read_from_bytes(chunk).ok()?);
+            stack.len += 1;
+        }
+        Some((stack, rest))
+    }
+
+    /// The size of a single element, refusing to compile for zero-sized elements (which cannot be counted in bytes).
+    const ELEM_SIZE: usize = {
+        assert!(size_of::<T>() > 0, "Cannot read StackVecs of zero-sized types from bytes");
+        size_of::<T>()
+    };
+}
+impl<const LEN: usize, T: IntoBytes + Immutable, P> StackVec<LEN, T, P> {
+    /// Writes the elements of this StackVec to the start of the given raw bytes, such as a network buffer.
+    ///
+    /// # Arguments
+    /// - `buf`: The buffer to write the elements to.
+    ///
+    /// # Returns
+    /// The remainder of `buf` after the written elements, or [`None`] if `buf` is too short (in which case it isn't touched).
+    #[inline]
+    pub fn write_to_prefix<'b>(&self, buf: &'b mut [u8]) -> Option<&'b mut [u8]> {
+        let bytes: &[u8] = self.as_slice().as_bytes();
+        if buf.len() < bytes.len() {
+            return None;
+        }
+        let (head, rest): (&mut [u8], &mut [u8]) = buf.split_at_mut(bytes.len());
+        head.copy_from_slice(bytes);
+        Some(rest)
+    }
+}